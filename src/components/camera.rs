@@ -0,0 +1,183 @@
+use math::{Mat4, Vec2};
+use components::{Transform};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Projection {
+    Perspective,
+    Orthographic(f32, f32, f32, f32),
+    Screen,
+}
+
+#[derive(Debug, Clone)]
+pub struct Camera {
+    projection: Projection,
+    fov: f32,
+    near: f32,
+    far: f32,
+    resolution: Vec2,
+    projection_matrix: Mat4,
+    view: Mat4,
+}
+
+impl Camera {
+    pub fn new_perspective(fov: f32, near: f32, far: f32, resolution: Vec2) -> Camera {
+        Camera::new(Projection::Perspective, fov, near, far, resolution)
+    }
+
+    pub fn new_orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Camera {
+        Camera::new(Projection::Orthographic(left, right, bottom, top), 0.0, near, far, Vec2::one())
+    }
+
+    pub fn new_screen(resolution: Vec2) -> Camera {
+        Camera::new(Projection::Screen, 0.0, -1.0, 1.0, resolution)
+    }
+
+    fn new(projection: Projection, fov: f32, near: f32, far: f32, resolution: Vec2) -> Camera {
+        let mut camera = Camera {
+            projection: projection,
+            fov: fov,
+            near: near,
+            far: far,
+            resolution: resolution,
+            projection_matrix: Mat4::identity(),
+            view: Mat4::identity(),
+        };
+        camera.rebuild_projection();
+        camera
+    }
+
+    fn rebuild_projection(&mut self) {
+        self.projection_matrix = match self.projection {
+            Projection::Perspective => Mat4::perspective(self.near, self.far, self.fov, self.get_aspect_ratio()),
+            Projection::Orthographic(left, right, bottom, top) => Mat4::orthographic_bounds(left, right, bottom, top, self.near, self.far),
+            Projection::Screen => Mat4::screen_space(self.resolution, self.near, self.far),
+        };
+    }
+
+    pub fn set_projection(&mut self, projection: Projection) {
+        self.projection = projection;
+        self.rebuild_projection();
+    }
+
+    pub fn set_fov(&mut self, fov: f32) {
+        self.fov = fov;
+        self.rebuild_projection();
+    }
+
+    pub fn set_near(&mut self, near: f32) {
+        self.near = near;
+        self.rebuild_projection();
+    }
+
+    pub fn set_far(&mut self, far: f32) {
+        self.far = far;
+        self.rebuild_projection();
+    }
+
+    // Minimized windows resize to zero, keep the last projection until they come back
+    pub fn set_resolution(&mut self, resolution: Vec2) {
+        if resolution[0] <= 0.0 || resolution[1] <= 0.0 {
+            return;
+        }
+        self.resolution = resolution;
+        self.rebuild_projection();
+    }
+
+    pub fn set_view(&mut self, view: Mat4) {
+        self.view = view;
+    }
+
+    pub fn get_projection(&self) -> Projection {
+        self.projection
+    }
+
+    pub fn get_fov(&self) -> f32 {
+        self.fov
+    }
+
+    pub fn get_near(&self) -> f32 {
+        self.near
+    }
+
+    pub fn get_far(&self) -> f32 {
+        self.far
+    }
+
+    pub fn get_resolution(&self) -> Vec2 {
+        self.resolution
+    }
+
+    pub fn get_aspect_ratio(&self) -> f32 {
+        self.resolution[0] / self.resolution[1].max(1.0)
+    }
+
+    pub fn get_projection_matrix(&self) -> Mat4 {
+        self.projection_matrix
+    }
+
+    pub fn get_view_matrix(&self, transform: Option<&Transform>) -> Mat4 {
+        match transform {
+            Some(transform) => transform.get_world_matrix().to_transpose().to_inverse(),
+            None => self.view,
+        }
+    }
+
+    pub fn get_interpolated_view_matrix(&self, transform: Option<&Transform>, alpha: f64) -> Mat4 {
+        match transform {
+            Some(transform) => transform.get_interpolated_world_matrix(alpha).to_transpose().to_inverse(),
+            None => self.view,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use math::{Vec2, Vec3, Mat4, Quat, assert_mat4_close};
+    use components::{Camera, Transform};
+
+    #[test]
+    fn transform_view_matches_explicit_view() {
+        let position = Vec3::from([0.0, 0.0, 5.0]);
+        let mut camera = Camera::new_perspective(90.0, 0.1, 100.0, Vec2::from([640.0, 480.0]));
+        camera.set_view(Mat4::view(0.0, 0.0, position));
+        let mut transform = Transform::new();
+        transform.set_position(position);
+        assert_mat4_close(camera.get_view_matrix(Some(&transform)), camera.get_view_matrix(None));
+    }
+
+    #[test]
+    fn transform_view_matches_explicit_view_with_yaw() {
+        let position = Vec3::from([1.0, 2.0, 5.0]);
+        let yaw = 0.6;
+        let mut transform = Transform::new();
+        transform.set_position(position);
+        transform.set_rotation(Quat::from_axis_angle(Vec3::from([0.0, 1.0, 0.0]), yaw));
+        let camera = Camera::new_screen(Vec2::from([640.0, 480.0]));
+        assert_mat4_close(camera.get_view_matrix(Some(&transform)), Mat4::view(0.0, yaw, position));
+    }
+
+    #[test]
+    fn interpolated_view_follows_the_transform_between_ticks() {
+        let camera = Camera::new_perspective(90.0, 0.1, 100.0, Vec2::from([640.0, 480.0]));
+        let mut transform = Transform::new();
+        transform.set_position(Vec3::from([0.0, 0.0, 5.0]));
+        transform.tick_mut();
+        transform.set_position(Vec3::from([0.0, 0.0, 7.0]));
+        let mut halfway = Transform::new();
+        halfway.set_position(Vec3::from([0.0, 0.0, 6.0]));
+        assert_mat4_close(camera.get_interpolated_view_matrix(Some(&transform), 0.5), camera.get_view_matrix(Some(&halfway)));
+        assert_mat4_close(camera.get_interpolated_view_matrix(Some(&transform), 1.0), camera.get_view_matrix(Some(&transform)));
+    }
+
+    #[test]
+    fn zero_sized_resolution_keeps_the_previous_projection() {
+        let mut camera = Camera::new_perspective(90.0, 0.1, 100.0, Vec2::from([640.0, 480.0]));
+        let projection = camera.get_projection_matrix();
+        camera.set_resolution(Vec2::from([640.0, 0.0]));
+        camera.set_resolution(Vec2::zero());
+        assert_eq!(camera.get_resolution(), Vec2::from([640.0, 480.0]));
+        assert_mat4_close(camera.get_projection_matrix(), projection);
+        let camera = Camera::new_perspective(90.0, 0.1, 100.0, Vec2::from([640.0, 0.0]));
+        assert!(camera.get_aspect_ratio().is_finite());
+    }
+}
@@ -0,0 +1,496 @@
+use std::fs::{File};
+use std::io::{Read};
+use std::path::{Path, PathBuf};
+use yaml_rust::{Yaml, YamlLoader};
+
+use logic::{Entity, World, Id, IdManager};
+use math::{Mat4, Vec3, Vec4};
+use graphics::solid_color::{Vertex as SolidColorVertex};
+use graphics::vertex_color::{Vertex as VertexColorVertex};
+use graphics::texture2d::{Vertex as Tex2Vertex};
+use components::{Transform, Named, Renderable, RenderableSolidColor, RenderableVertexColor, RenderableTex2};
+use err::DorpErr;
+
+macro_rules! draw_method_from_yaml {
+    ($module: ident, $yaml: expr) => {{
+        use graphics::$module::{DrawMethod, DepthTestMethod, CullingMethod};
+        let yaml: &Yaml = $yaml;
+        let depth = match yaml["depth"].as_str() {
+            Some("IfLess") => Some(DepthTestMethod::IfLess),
+            Some(_) => return Err(DorpErr::Base("Unknown depth test method")),
+            None => None,
+        };
+        let culling = match yaml["culling"].as_str() {
+            Some("Clockwise") => Some(CullingMethod::Clockwise),
+            Some("CounterClockwise") => Some(CullingMethod::CounterClockwise),
+            Some(_) => return Err(DorpErr::Base("Unknown culling method")),
+            None => None,
+        };
+        match (depth, culling) {
+            (Some(depth), Some(culling)) => DrawMethod::Both(depth, culling),
+            (Some(depth), None) => DrawMethod::Depth(depth),
+            (None, Some(culling)) => DrawMethod::Culling(culling),
+            (None, None) => DrawMethod::Neither,
+        }
+    }}
+}
+
+#[derive(Debug, Clone)]
+pub struct EntityDesc {
+    type_name: String,
+    name: Option<String>,
+    base_path: PathBuf,
+    yaml: Yaml,
+}
+
+impl EntityDesc {
+    pub fn from_yaml(yaml: &Yaml, base_path: &Path) -> Result<EntityDesc, DorpErr> {
+        let type_name = match yaml["type"].as_str() {
+            Some(type_name) => type_name.to_string(),
+            None => return Err(DorpErr::Base("Entity description type was missing")),
+        };
+        let name = match yaml["name"] {
+            Yaml::BadValue | Yaml::Null => None,
+            Yaml::String(ref name) => Some(name.clone()),
+            _ => return Err(DorpErr::Base("Entity description name was not a string")),
+        };
+        Ok(
+            EntityDesc {
+                type_name: type_name,
+                name: name,
+                base_path: base_path.to_path_buf(),
+                yaml: yaml.clone(),
+            }
+        )
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<EntityDesc, DorpErr> {
+        let path = path.as_ref();
+        let text = match read_text(path) {
+            Ok(text) => text,
+            Err(err) => return Err(DorpErr::Dorp("Read Text", Box::new(err))),
+        };
+        let docs = match YamlLoader::load_from_str(&text) {
+            Ok(docs) => docs,
+            Err(err) => return Err(DorpErr::YamlScan("Yaml Loader Load From Str", err)),
+        };
+        match docs.get(0) {
+            Some(doc) => EntityDesc::from_yaml(doc, &parent_path(path)),
+            None => Err(DorpErr::Base("Entity description file was empty")),
+        }
+    }
+
+    pub fn load_all<P: AsRef<Path>>(path: P) -> Result<Vec<EntityDesc>, DorpErr> {
+        let path = path.as_ref();
+        let text = match read_text(path) {
+            Ok(text) => text,
+            Err(err) => return Err(DorpErr::Dorp("Read Text", Box::new(err))),
+        };
+        EntityDesc::load_all_from_str(&text, &parent_path(path))
+    }
+
+    pub fn load_all_from_str(text: &str, base_path: &Path) -> Result<Vec<EntityDesc>, DorpErr> {
+        let docs = match YamlLoader::load_from_str(text) {
+            Ok(docs) => docs,
+            Err(err) => return Err(DorpErr::YamlScan("Yaml Loader Load From Str", err)),
+        };
+        let mut descs = vec!();
+        if let Some(doc) = docs.get(0) {
+            if let Some(entities) = doc["entities"].as_vec() {
+                for entity in entities.iter() {
+                    match EntityDesc::from_yaml(entity, base_path) {
+                        Ok(desc) => descs.push(desc),
+                        Err(err) => return Err(DorpErr::Dorp("Entity Desc From Yaml", Box::new(err))),
+                    }
+                }
+            }
+        }
+        Ok(descs)
+    }
+
+    pub fn get_type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    pub fn get_name(&self) -> Option<&str> {
+        match self.name {
+            Some(ref name) => Some(name),
+            None => None,
+        }
+    }
+
+    pub fn get_yaml(&self) -> &Yaml {
+        &self.yaml
+    }
+
+    pub fn get_base_path(&self) -> &Path {
+        &self.base_path
+    }
+
+    pub fn build_transform(&self) -> Result<Option<Transform>, DorpErr> {
+        let yaml = &self.yaml["transform"];
+        if is_missing(yaml) {
+            return Ok(None);
+        }
+        let mut transform = Transform::new();
+        if !is_missing(&yaml["position"]) {
+            match vec3_from_yaml(&yaml["position"]) {
+                Ok(position) => transform.set_position(position),
+                Err(err) => return Err(DorpErr::Dorp("Transform Position From Yaml", Box::new(err))),
+            }
+        }
+        if !is_missing(&yaml["rotation"]) {
+            match vec3_from_yaml(&yaml["rotation"]) {
+                Ok(rotation) => transform.set_rotation_euler(rotation),
+                Err(err) => return Err(DorpErr::Dorp("Transform Rotation From Yaml", Box::new(err))),
+            }
+        }
+        if !is_missing(&yaml["scalation"]) {
+            match vec3_from_yaml(&yaml["scalation"]) {
+                Ok(scalation) => transform.set_scalation(scalation),
+                Err(err) => return Err(DorpErr::Dorp("Transform Scalation From Yaml", Box::new(err))),
+            }
+        }
+        transform.snap();
+        Ok(Some(transform))
+    }
+
+    // Registers the name right away, so build it after anything else that can still fail
+    pub fn build_named<T: Entity<T>>(&self, id: Id, world: &mut World<T>) -> Result<Option<Named>, DorpErr> {
+        match self.name {
+            Some(ref name) => match Named::new(name, id, world) {
+                Ok(named) => Ok(Some(named)),
+                Err(err) => Err(DorpErr::Dorp("Named New", Box::new(err))),
+            },
+            None => Ok(None),
+        }
+    }
+
+    pub fn build_parts<T: Entity<T>>(&self, id: Id, manager: &mut IdManager, world: &mut World<T>) -> Result<(Option<Transform>, Option<Renderable>, Option<Named>), DorpErr> {
+        let transform = match self.build_transform() {
+            Ok(transform) => transform,
+            Err(err) => return Err(DorpErr::Dorp("Self Build Transform", Box::new(err))),
+        };
+        let renderable = match self.build_renderable(manager, world) {
+            Ok(renderable) => renderable,
+            Err(err) => return Err(DorpErr::Dorp("Self Build Renderable", Box::new(err))),
+        };
+        match self.build_named(id, world) {
+            Ok(named) => Ok((transform, renderable, named)),
+            Err(err) => Err(DorpErr::Dorp("Self Build Named", Box::new(err))),
+        }
+    }
+
+    pub fn build_renderable<T: Entity<T>>(&self, manager: &mut IdManager, world: &World<T>) -> Result<Option<Renderable>, DorpErr> {
+        let yaml = &self.yaml["renderable"];
+        if is_missing(yaml) {
+            return Ok(None);
+        }
+        let perspective = match perspective_from_yaml(&yaml["perspective"], world.get_aspect_ratio()) {
+            Ok(perspective) => perspective,
+            Err(err) => return Err(DorpErr::Dorp("Perspective From Yaml", Box::new(err))),
+        };
+        let view = match view_from_yaml(&yaml["view"]) {
+            Ok(view) => view,
+            Err(err) => return Err(DorpErr::Dorp("View From Yaml", Box::new(err))),
+        };
+        let indices = match indices_from_yaml(&yaml["indices"]) {
+            Ok(indices) => indices,
+            Err(err) => return Err(DorpErr::Dorp("Indices From Yaml", Box::new(err))),
+        };
+        let mut renderable = Renderable::new();
+        if !is_missing(&yaml["solid_color"]) {
+            let yaml = &yaml["solid_color"];
+            let mut vertices = vec!();
+            for vertex in yaml["vertices"].as_vec().unwrap_or(&vec!()).iter() {
+                match vec3_from_yaml(vertex) {
+                    Ok(position) => vertices.push(SolidColorVertex::new(position.get_vals())),
+                    Err(err) => return Err(DorpErr::Dorp("Solid Color Vertex From Yaml", Box::new(err))),
+                }
+            }
+            let color = match vec4_from_yaml(&yaml["color"]) {
+                Ok(color) => color,
+                Err(err) => return Err(DorpErr::Dorp("Solid Color Color From Yaml", Box::new(err))),
+            };
+            let mut solid_color = RenderableSolidColor::new(manager);
+            solid_color.set_vertices(vertices);
+            solid_color.set_indices(indices);
+            solid_color.set_color(color);
+            solid_color.set_draw_method(draw_method_from_yaml!(solid_color, &yaml["draw_method"]));
+            solid_color.set_perspective(perspective);
+            solid_color.set_view(view);
+            solid_color.set_model(Mat4::identity());
+            renderable.set_solid_color(solid_color);
+        } else if !is_missing(&yaml["vertex_color"]) {
+            let yaml = &yaml["vertex_color"];
+            let mut vertices = vec!();
+            for vertex in yaml["vertices"].as_vec().unwrap_or(&vec!()).iter() {
+                match (vec3_from_yaml(&vertex["position"]), vec4_from_yaml(&vertex["color"])) {
+                    (Ok(position), Ok(color)) => vertices.push(VertexColorVertex::new(position.get_vals(), color.get_vals())),
+                    (Err(err), _) => return Err(DorpErr::Dorp("Vertex Color Position From Yaml", Box::new(err))),
+                    (_, Err(err)) => return Err(DorpErr::Dorp("Vertex Color Color From Yaml", Box::new(err))),
+                }
+            }
+            let mut vertex_color = RenderableVertexColor::new(manager);
+            vertex_color.set_vertices(vertices);
+            vertex_color.set_indices(indices);
+            vertex_color.set_draw_method(draw_method_from_yaml!(vertex_color, &yaml["draw_method"]));
+            vertex_color.set_perspective(perspective);
+            vertex_color.set_view(view);
+            vertex_color.set_model(Mat4::identity());
+            renderable.set_vertex_color(vertex_color);
+        } else if !is_missing(&yaml["texture2d"]) {
+            let yaml = &yaml["texture2d"];
+            let mut vertices = vec!();
+            for vertex in yaml["vertices"].as_vec().unwrap_or(&vec!()).iter() {
+                match (vec3_from_yaml(&vertex["position"]), floats_from_yaml(&vertex["tex_coord"], 2)) {
+                    (Ok(position), Ok(tex_coord)) => vertices.push(Tex2Vertex::new(position.get_vals(), [tex_coord[0], tex_coord[1]])),
+                    (Err(err), _) => return Err(DorpErr::Dorp("Texture2d Position From Yaml", Box::new(err))),
+                    (_, Err(err)) => return Err(DorpErr::Dorp("Texture2d Tex Coord From Yaml", Box::new(err))),
+                }
+            }
+            let texture = match yaml["texture"].as_str() {
+                Some(texture) => match read_bytes(&self.base_path.join(texture)) {
+                    Ok(texture) => texture,
+                    Err(err) => return Err(DorpErr::DorpString("Read Texture: ".to_string() + texture, Box::new(err))),
+                },
+                None => return Err(DorpErr::Base("Texture2d texture path was missing")),
+            };
+            let mut texture2d = RenderableTex2::new(manager);
+            texture2d.set_vertices(vertices);
+            texture2d.set_indices(indices);
+            texture2d.set_texture_data(texture);
+            texture2d.set_draw_method(draw_method_from_yaml!(texture2d, &yaml["draw_method"]));
+            texture2d.set_perspective(perspective);
+            texture2d.set_view(view);
+            texture2d.set_model(Mat4::identity());
+            renderable.set_texture2d(texture2d);
+        } else {
+            return Err(DorpErr::Base("Renderable was not solid_color, vertex_color or texture2d"));
+        }
+        Ok(Some(renderable))
+    }
+}
+
+fn is_missing(yaml: &Yaml) -> bool {
+    match *yaml {
+        Yaml::BadValue | Yaml::Null => true,
+        _ => false,
+    }
+}
+
+fn parent_path(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) => parent.to_path_buf(),
+        None => PathBuf::new(),
+    }
+}
+
+fn read_text(path: &Path) -> Result<String, DorpErr> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => return Err(DorpErr::Io("File Open", err)),
+    };
+    let mut text = String::new();
+    match file.read_to_string(&mut text) {
+        Ok(_) => Ok(text),
+        Err(err) => Err(DorpErr::Io("File Read To String", err)),
+    }
+}
+
+fn read_bytes(path: &Path) -> Result<Vec<u8>, DorpErr> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => return Err(DorpErr::Io("File Open", err)),
+    };
+    let mut bytes = vec!();
+    match file.read_to_end(&mut bytes) {
+        Ok(_) => Ok(bytes),
+        Err(err) => Err(DorpErr::Io("File Read To End", err)),
+    }
+}
+
+fn f32_from_yaml(yaml: &Yaml) -> Result<f32, DorpErr> {
+    match *yaml {
+        Yaml::Integer(value) => Ok(value as f32),
+        _ => match yaml.as_f64() {
+            Some(value) => Ok(value as f32),
+            None => Err(DorpErr::Base("Yaml value was not a number")),
+        },
+    }
+}
+
+fn floats_from_yaml(yaml: &Yaml, count: usize) -> Result<Vec<f32>, DorpErr> {
+    let values = match yaml.as_vec() {
+        Some(values) if values.len() == count => values,
+        _ => return Err(DorpErr::Base("Yaml value was not an array of the expected length")),
+    };
+    let mut floats = vec!();
+    for value in values.iter() {
+        match f32_from_yaml(value) {
+            Ok(value) => floats.push(value),
+            Err(err) => return Err(DorpErr::Dorp("F32 From Yaml", Box::new(err))),
+        }
+    }
+    Ok(floats)
+}
+
+fn vec3_from_yaml(yaml: &Yaml) -> Result<Vec3, DorpErr> {
+    match floats_from_yaml(yaml, 3) {
+        Ok(floats) => Ok(Vec3::from([floats[0], floats[1], floats[2]])),
+        Err(err) => Err(DorpErr::Dorp("Floats From Yaml", Box::new(err))),
+    }
+}
+
+fn vec4_from_yaml(yaml: &Yaml) -> Result<Vec4, DorpErr> {
+    match floats_from_yaml(yaml, 4) {
+        Ok(floats) => Ok(Vec4::from([floats[0], floats[1], floats[2], floats[3]])),
+        Err(err) => Err(DorpErr::Dorp("Floats From Yaml", Box::new(err))),
+    }
+}
+
+fn indices_from_yaml(yaml: &Yaml) -> Result<Vec<u32>, DorpErr> {
+    let mut indices = vec!();
+    for index in yaml.as_vec().unwrap_or(&vec!()).iter() {
+        match index.as_i64() {
+            Some(index) if index >= 0 => indices.push(index as u32),
+            _ => return Err(DorpErr::Base("Index was not a non-negative integer")),
+        }
+    }
+    Ok(indices)
+}
+
+fn perspective_from_yaml(yaml: &Yaml, aspect_ratio: f32) -> Result<Mat4, DorpErr> {
+    if is_missing(yaml) {
+        return Ok(Mat4::identity());
+    }
+    if !is_missing(&yaml["left"]) {
+        return match (f32_from_yaml(&yaml["left"]), f32_from_yaml(&yaml["right"]), f32_from_yaml(&yaml["bottom"]), f32_from_yaml(&yaml["top"]), f32_from_yaml(&yaml["near"]), f32_from_yaml(&yaml["far"])) {
+            (Ok(left), Ok(right), Ok(bottom), Ok(top), Ok(near), Ok(far)) => Ok(Mat4::orthographic_bounds(left, right, bottom, top, near, far)),
+            _ => Err(DorpErr::Base("Orthographic projection needs left, right, bottom, top, near and far numbers")),
+        };
+    }
+    match (f32_from_yaml(&yaml["near"]), f32_from_yaml(&yaml["far"]), f32_from_yaml(&yaml["fov"])) {
+        (Ok(near), Ok(far), Ok(fov)) => Ok(Mat4::perspective(near, far, fov, aspect_ratio)),
+        _ => Err(DorpErr::Base("Perspective needs near, far and fov numbers")),
+    }
+}
+
+fn view_from_yaml(yaml: &Yaml) -> Result<Mat4, DorpErr> {
+    if is_missing(yaml) {
+        return Ok(Mat4::identity());
+    }
+    match (f32_from_yaml(&yaml["pitch"]), f32_from_yaml(&yaml["yaw"]), vec3_from_yaml(&yaml["position"])) {
+        (Ok(pitch), Ok(yaw), Ok(position)) => Ok(Mat4::view_deg(pitch, yaw, position)),
+        _ => Err(DorpErr::Base("View needs pitch and yaw numbers and a position")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path};
+
+    use logic::{Entity, Id, IdManager, test_world, build_test_entity};
+    use components::{EntityDesc, EntityRegistry};
+    use math::{Vec3, Ray};
+    use err::DorpErr;
+
+    const SCENE: &'static str = "
+entities:
+  - type: prop
+    name: crate
+    transform:
+      position: [1, 2, 3]
+    renderable:
+      solid_color:
+        vertices: [[-1, -1, 0], [1, -1, 0], [0, 1, 0]]
+        color: [1, 0, 0, 1]
+        draw_method:
+          culling: Clockwise
+      indices: [0, 1, 2]
+  - type: prop
+";
+
+    fn desc(text: &str) -> Result<EntityDesc, DorpErr> {
+        match EntityDesc::load_all_from_str(&format!("entities:\n  - {}\n", text), Path::new("")) {
+            Ok(mut descs) => Ok(descs.remove(0)),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[test]
+    fn descriptions_keep_type_name_and_base_path() {
+        let descs = EntityDesc::load_all_from_str(SCENE, Path::new("assets")).unwrap();
+        assert_eq!(descs.len(), 2);
+        assert_eq!(descs[0].get_type_name(), "prop");
+        assert_eq!(descs[0].get_name(), Some("crate"));
+        assert_eq!(descs[0].get_base_path(), Path::new("assets"));
+        assert_eq!(descs[1].get_name(), None);
+    }
+
+    #[test]
+    fn parse_errors_are_reported() {
+        assert!(EntityDesc::load_all_from_str("entities: [", Path::new("")).is_err());
+        assert!(desc("name: crate").is_err());
+        assert!(desc("{type: prop, name: [crate]}").is_err());
+        assert!(desc("{type: prop, transform: {position: [1, 2]}}").unwrap().build_transform().is_err());
+        assert!(desc("{type: prop, transform: {scalation: [1, a, 1]}}").unwrap().build_transform().is_err());
+        let mut manager = IdManager::new();
+        let world = test_world();
+        assert!(desc("{type: prop, renderable: {indices: [0]}}").unwrap().build_renderable(&mut manager, &world).is_err());
+        assert!(desc("{type: prop, renderable: {indices: [-1], solid_color: {vertices: [], color: [1, 1, 1, 1]}}}").unwrap().build_renderable(&mut manager, &world).is_err());
+        assert!(desc("{type: prop, renderable: {solid_color: {vertices: [], color: [1, 1, 1, 1], draw_method: {culling: Sideways}}}}").unwrap().build_renderable(&mut manager, &world).is_err());
+        assert!(desc("{type: prop, renderable: {perspective: {near: 1}, solid_color: {vertices: [], color: [1, 1, 1, 1]}}}").unwrap().build_renderable(&mut manager, &world).is_err());
+    }
+
+    #[test]
+    fn missing_sections_build_nothing() {
+        let desc = desc("type: prop").unwrap();
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let id = Id::new(&mut manager);
+        assert!(desc.build_transform().unwrap().is_none());
+        assert!(desc.build_named(id, &mut world).unwrap().is_none());
+        assert!(desc.build_renderable(&mut manager, &world).unwrap().is_none());
+    }
+
+    #[test]
+    fn failed_parts_leave_the_name_free() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let id = Id::new(&mut manager);
+        let broken = desc("{type: prop, name: crate, renderable: {indices: [0]}}").unwrap();
+        assert!(broken.build_parts(id, &mut manager, &mut world).is_err());
+        assert!(world.get_names().get("crate").is_none());
+        let fixed = desc("{type: prop, name: crate, transform: {position: [1, 2, 3]}}").unwrap();
+        let (transform, renderable, named) = fixed.build_parts(id, &mut manager, &mut world).unwrap();
+        assert!(transform.is_some());
+        assert!(renderable.is_none());
+        assert_eq!(named.map(|named| named.get_name().to_string()), Some("crate".to_string()));
+        assert_eq!(world.get_names().get("crate"), Some(&id));
+    }
+
+    #[test]
+    fn descriptions_round_trip_into_a_world() {
+        let mut registry = EntityRegistry::new();
+        registry.register("prop", Box::new(build_test_entity));
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        for desc in EntityDesc::load_all_from_str(SCENE, Path::new("")).unwrap().iter() {
+            let entity = registry.create(desc, &mut manager, &mut world).unwrap();
+            world.add_entity(entity);
+        }
+        world.update_transforms();
+        assert_eq!(world.get_entities().len(), 2);
+        let id = match world.get_entity_by_name("crate") {
+            Some(entity) => {
+                assert_eq!(entity.get_transform().map(|transform| transform.get_position()), Some(Vec3::from([1.0, 2.0, 3.0])));
+                assert!(entity.get_renderable().and_then(|renderable| renderable.get_solid_color()).is_some());
+                entity.get_id()
+            },
+            None => panic!("Named entity was not registered"),
+        };
+        let ray = Ray::new(Vec3::from([1.0, 2.0, 10.0]), Vec3::from([0.0, 0.0, -1.0]));
+        assert_eq!(world.pick(ray).map(|(hit, _)| hit), Some(id));
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::{HashMap};
+
+use logic::{IdManager, World, Entity};
+use components::{EntityDesc};
+use err::DorpErr;
+
+pub type EntityFactory<T> = Box<Fn(&EntityDesc, &mut IdManager, &mut World<T>) -> Result<T, DorpErr> + Send + Sync>;
+
+pub struct EntityRegistry<T: Entity<T>> {
+    factories: HashMap<String, EntityFactory<T>>,
+}
+
+impl<T: Entity<T>> EntityRegistry<T> {
+    pub fn new() -> EntityRegistry<T> {
+        EntityRegistry {
+            factories: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, type_name: &str, factory: EntityFactory<T>) {
+        self.factories.insert(type_name.to_string(), factory);
+    }
+
+    pub fn deregister(&mut self, type_name: &str) {
+        self.factories.remove(type_name);
+    }
+
+    pub fn is_registered(&self, type_name: &str) -> bool {
+        self.factories.contains_key(type_name)
+    }
+
+    pub fn create(&self, desc: &EntityDesc, manager: &mut IdManager, world: &mut World<T>) -> Result<T, DorpErr> {
+        match self.factories.get(desc.get_type_name()) {
+            Some(factory) => match factory(desc, manager, world) {
+                Ok(entity) => Ok(entity),
+                Err(err) => Err(DorpErr::DorpString("Entity Factory: ".to_string() + desc.get_type_name(), Box::new(err))),
+            },
+            None => Err(DorpErr::BaseString("No entity factory registered for type: ".to_string() + desc.get_type_name())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path};
+
+    use logic::{World, Entity, IdManager, TestEntity, test_world, build_test_entity};
+    use components::{EntityDesc, EntityRegistry};
+    use err::DorpErr;
+
+    fn build_broken(_: &EntityDesc, _: &mut IdManager, _: &mut World<TestEntity>) -> Result<TestEntity, DorpErr> {
+        Err(DorpErr::Base("Broken factory"))
+    }
+
+    fn descs() -> Vec<EntityDesc> {
+        EntityDesc::load_all_from_str("entities:\n  - type: marker\n  - type: broken\n", Path::new("")).unwrap()
+    }
+
+    #[test]
+    fn create_uses_the_factory_for_the_type() {
+        let mut registry = EntityRegistry::new();
+        registry.register("marker", Box::new(build_test_entity));
+        registry.register("broken", Box::new(build_broken));
+        let descs = descs();
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let marker = registry.create(&descs[0], &mut manager, &mut world).unwrap();
+        assert!(manager.is_alive(marker.get_id()));
+        assert!(registry.create(&descs[1], &mut manager, &mut world).is_err());
+    }
+
+    #[test]
+    fn unregistered_types_are_errors() {
+        let mut registry = EntityRegistry::new();
+        registry.register("marker", Box::new(build_test_entity));
+        assert!(registry.is_registered("marker"));
+        registry.deregister("marker");
+        assert!(!registry.is_registered("marker"));
+        assert!(registry.create(&descs()[0], &mut IdManager::new(), &mut test_world()).is_err());
+    }
+}
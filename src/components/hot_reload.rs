@@ -0,0 +1,178 @@
+use std::collections::{HashMap};
+use std::fs::{File};
+use std::io::{Read};
+use std::path::{Path, PathBuf};
+
+use logic::{IdManager, World, Entity, TextureId, FileWatcher};
+use graphics::{Window, SyncData, Renderers};
+use components::{SceneManager};
+use err::DorpErr;
+
+// Game polls the one given to Game::set_hot_reload against its own scenes, any other needs tick_mut and render called by hand
+#[derive(Debug)]
+pub struct HotReload {
+    watcher: FileWatcher,
+    textures: HashMap<PathBuf, Vec<TextureId>>,
+    scenes: Vec<PathBuf>,
+    pending_textures: Vec<PathBuf>,
+    errors: Vec<(PathBuf, DorpErr)>,
+}
+
+impl HotReload {
+    pub fn new(poll_interval: f64) -> HotReload {
+        HotReload {
+            watcher: FileWatcher::new(poll_interval),
+            textures: HashMap::new(),
+            scenes: vec!(),
+            pending_textures: vec!(),
+            errors: vec!(),
+        }
+    }
+
+    pub fn watch_texture<P: AsRef<Path>>(&mut self, path: P, texture_id: TextureId) {
+        let path = path.as_ref().to_path_buf();
+        self.watcher.watch(&path);
+        self.textures.entry(path).or_insert(vec!()).push(texture_id);
+    }
+
+    pub fn watch_scene<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref().to_path_buf();
+        self.watcher.watch(&path);
+        if !self.scenes.contains(&path) {
+            self.scenes.push(path);
+        }
+    }
+
+    pub fn unwatch<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref();
+        self.watcher.unwatch(path);
+        self.textures.remove(path);
+        self.scenes.retain(|scene| scene.as_path() != path);
+    }
+
+    pub fn get_watcher(&self) -> &FileWatcher {
+        &self.watcher
+    }
+
+    pub fn get_mut_watcher(&mut self) -> &mut FileWatcher {
+        &mut self.watcher
+    }
+
+    pub fn take_errors(&mut self) -> Vec<(PathBuf, DorpErr)> {
+        let len = self.errors.len();
+        self.errors.drain(0..len).collect()
+    }
+
+    pub fn tick_mut<T: Entity<T>>(&mut self, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData, scene_manager: &mut SceneManager<T>) {
+        for path in self.watcher.poll() {
+            if self.textures.contains_key(&path) && !self.pending_textures.contains(&path) {
+                self.pending_textures.push(path.clone());
+            }
+            if self.scenes.contains(&path) {
+                match scene_manager.reload_file(&path, manager, world, sync_data) {
+                    Ok(_) => (),
+                    Err(err) => self.errors.push((path.clone(), DorpErr::Dorp("Scene Manager Reload File", Box::new(err)))),
+                }
+            }
+        }
+    }
+
+    // Read failures are kept for take_errors instead of stopping the other textures
+    fn read_pending_textures(&mut self) -> Vec<(PathBuf, Vec<u8>)> {
+        let len = self.pending_textures.len();
+        let mut loaded = vec!();
+        for path in self.pending_textures.drain(0..len) {
+            let mut data = vec!();
+            match File::open(&path) {
+                Ok(mut file) => match file.read_to_end(&mut data) {
+                    Ok(_) => loaded.push((path, data)),
+                    Err(err) => self.errors.push((path, DorpErr::Io("File Read To End", err))),
+                },
+                Err(err) => self.errors.push((path, DorpErr::Io("File Open", err))),
+            }
+        }
+        loaded
+    }
+
+    pub fn render(&mut self, window: &mut Window, renderers: &mut Renderers) {
+        for (path, data) in self.read_pending_textures() {
+            if let Some(texture_ids) = self.textures.get(&path) {
+                for texture_id in texture_ids.iter() {
+                    match renderers.get_mut_texture2d().set_texture(*texture_id, window, &data) {
+                        Ok(()) => (),
+                        Err(err) => self.errors.push((path.clone(), DorpErr::Dorp("Renderers Get Mut Tex2 Set Texture", Box::new(err)))),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::{temp_dir};
+    use std::fs::{File, remove_file};
+    use std::io::{Write};
+    use std::path::{Path};
+    use std::sync::{Arc};
+
+    use logic::{World, Id, IdManager, TextureId, TestEntity, test_world, build_test_entity};
+    use graphics::{SyncData};
+    use components::{HotReload, Scene, SceneManager, EntityRegistry};
+
+    fn write(path: &Path, text: &str) {
+        File::create(path).unwrap().write_all(text.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn failed_scene_reload_keeps_the_old_scene() {
+        let path = temp_dir().join("dorp_hot_reload_scene.yaml");
+        let _ = remove_file(&path);
+        let mut hot_reload = HotReload::new(0.0);
+        hot_reload.watch_scene(&path);
+        write(&path, "entities:\n  - type: piece\n    name: door\n");
+
+        let mut registry = EntityRegistry::new();
+        registry.register("piece", Box::new(build_test_entity));
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut sync_data = SyncData::new();
+        let mut scenes = SceneManager::new();
+        scenes.push(Scene::from_file(&path, Arc::new(registry)).unwrap());
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        let door = world.get_names().get("door").cloned().unwrap();
+
+        write(&path, "entities:\n  - type: missing\n");
+        hot_reload.tick_mut(&mut manager, &mut world, &mut sync_data, &mut scenes);
+        remove_file(&path).unwrap();
+        world.tick_mut();
+        let errors = hot_reload.take_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, path);
+        assert_eq!(scenes.get_depth(), 1);
+        assert!(scenes.is_owned_by_top(door));
+        assert!(world.is_entity_alive(door));
+        assert_eq!(world.get_names().get("door"), Some(&door));
+    }
+
+    #[test]
+    fn texture_read_errors_are_kept_until_taken() {
+        let path = temp_dir().join("dorp_hot_reload_texture.png");
+        let _ = remove_file(&path);
+        let mut manager = IdManager::new();
+        let texture_id: TextureId = Id::new(&mut manager);
+        let mut hot_reload = HotReload::new(0.0);
+        hot_reload.watch_texture(&path, texture_id);
+        write(&path, "not a png");
+
+        let mut world: World<TestEntity> = test_world();
+        hot_reload.tick_mut(&mut manager, &mut world, &mut SyncData::new(), &mut SceneManager::new());
+        remove_file(&path).unwrap();
+        assert!(hot_reload.take_errors().is_empty());
+        assert!(hot_reload.read_pending_textures().is_empty());
+        let errors = hot_reload.take_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, path);
+        assert!(hot_reload.take_errors().is_empty());
+    }
+}
@@ -6,12 +6,22 @@ mod map_2d_coords;
 mod map_3d;
 mod map_3d_coords;
 mod scene;
+mod entity_desc;
+mod entity_registry;
+mod hot_reload;
+mod prefab;
+mod camera;
 
 pub use self::transform::{Transform};
-pub use self::renderables::{Renderable, RenderableTex2, RenderableSolidColor, RenderableVertexColor};
+pub use self::renderables::{Renderable, RenderableTex2, RenderableSolidColor, RenderableVertexColor, Mesh};
 pub use self::named::{Named};
 pub use self::map_2d::{Map2d};
 pub use self::map_2d_coords::{Map2dCoords};
 pub use self::map_3d::{Map3d};
 pub use self::map_3d_coords::{Map3dCoords};
-pub use self::scene::{Scene};
+pub use self::scene::{Scene, SceneHook, SceneManager, SceneTransition};
+pub use self::entity_desc::{EntityDesc};
+pub use self::entity_registry::{EntityRegistry, EntityFactory};
+pub use self::hot_reload::{HotReload};
+pub use self::prefab::{Prefab, PrefabInstance};
+pub use self::camera::{Camera, Projection};
@@ -1,18 +1,20 @@
-use logic::{Entity, World, Id};
+use yaml_rust::{Yaml};
+
+use logic::{Entity, World, Id, IdManager, Persistent};
 use err::DorpErr;
 
 #[derive(Debug)]
 pub struct Named {
-    name: &'static str,
+    name: String,
 }
 
 impl Named {
-    pub fn new<T: Entity<T>>(name: &'static str, id: Id, world: &mut World<T>) -> Result<Named, DorpErr> {
+    pub fn new<T: Entity<T>>(name: &str, id: Id, world: &mut World<T>) -> Result<Named, DorpErr> {
         match world.register_name(id, name) {
             Ok(_) => {
                 Ok(
                     Named {
-                        name: name,
+                        name: name.to_string(),
                     }
                 )
             },
@@ -20,7 +22,27 @@ impl Named {
         }
     }
 
-    pub fn get_name(&self) -> &'static str {
-        self.name
+    // Doesn't touch the world's name table, snapshots restore that table separately
+    pub fn unregistered(name: &str) -> Named {
+        Named {
+            name: name.to_string(),
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Persistent for Named {
+    fn save(&self) -> Yaml {
+        Yaml::String(self.name.clone())
+    }
+
+    fn load(yaml: &Yaml, _: &mut IdManager) -> Result<Named, DorpErr> {
+        match yaml.as_str() {
+            Some(name) => Ok(Named::unregistered(name)),
+            None => Err(DorpErr::Base("Named Yaml was not a string")),
+        }
     }
 }
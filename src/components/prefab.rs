@@ -0,0 +1,215 @@
+use std::path::{Path};
+
+use logic::{IdManager, World, Entity, Id};
+use graphics::{Window, SyncData, Renderers};
+use components::{Transform, Renderable, Named, EntityDesc};
+use err::DorpErr;
+
+#[derive(Debug)]
+pub struct PrefabInstance {
+    id: Id,
+    transform: Option<Transform>,
+    renderable: Option<Renderable>,
+    named: Option<Named>,
+}
+
+impl PrefabInstance {
+    pub fn get_id(&self) -> Id {
+        self.id
+    }
+
+    pub fn take_transform(&mut self) -> Option<Transform> {
+        self.transform.take()
+    }
+
+    pub fn take_renderable(&mut self) -> Option<Renderable> {
+        self.renderable.take()
+    }
+
+    pub fn take_named(&mut self) -> Option<Named> {
+        self.named.take()
+    }
+
+    pub fn into_parts(self) -> (Id, Option<Transform>, Option<Renderable>, Option<Named>) {
+        (self.id, self.transform, self.renderable, self.named)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Prefab {
+    transform: Option<Transform>,
+    renderable: Option<Renderable>,
+    name: Option<String>,
+    spawn_count: u64,
+}
+
+impl Prefab {
+    pub fn new() -> Prefab {
+        Prefab {
+            transform: None,
+            renderable: None,
+            name: None,
+            spawn_count: 0,
+        }
+    }
+
+    pub fn from_desc<T: Entity<T>>(desc: &EntityDesc, manager: &mut IdManager, world: &World<T>) -> Result<Prefab, DorpErr> {
+        let mut prefab = Prefab::new();
+        match desc.build_transform() {
+            Ok(transform) => prefab.transform = transform,
+            Err(err) => return Err(DorpErr::Dorp("Entity Desc Build Transform", Box::new(err))),
+        }
+        match desc.build_renderable(manager, world) {
+            Ok(renderable) => prefab.renderable = renderable,
+            Err(err) => return Err(DorpErr::Dorp("Entity Desc Build Renderable", Box::new(err))),
+        }
+        prefab.name = desc.get_name().map(|name| name.to_string());
+        Ok(prefab)
+    }
+
+    pub fn load<T: Entity<T>, P: AsRef<Path>>(path: P, manager: &mut IdManager, world: &World<T>) -> Result<Prefab, DorpErr> {
+        match EntityDesc::load(path) {
+            Ok(desc) => Prefab::from_desc(&desc, manager, world),
+            Err(err) => Err(DorpErr::Dorp("Entity Desc Load", Box::new(err))),
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Prefab {
+        self.transform = Some(transform);
+        self
+    }
+
+    pub fn with_renderable(mut self, renderable: Renderable) -> Prefab {
+        self.renderable = Some(renderable);
+        self
+    }
+
+    pub fn with_name(mut self, name: &str) -> Prefab {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn get_transform(&self) -> Option<&Transform> {
+        self.transform.as_ref()
+    }
+
+    pub fn get_renderable(&self) -> Option<&Renderable> {
+        self.renderable.as_ref()
+    }
+
+    pub fn get_spawn_count(&self) -> u64 {
+        self.spawn_count
+    }
+
+    pub fn render(&mut self, window: &mut Window, sync_data: &mut SyncData, renderers: &mut Renderers) -> Result<(), DorpErr> {
+        match self.renderable {
+            Some(ref mut renderable) => match renderable.render(window, sync_data, renderers) {
+                Ok(()) => Ok(()),
+                Err(err) => Err(DorpErr::Dorp("Renderable Render", Box::new(err))),
+            },
+            None => Ok(()),
+        }
+    }
+
+    // The first instance spawned after the shared buffers change uploads them for all the others, unless Prefab::render got to them first
+    pub fn spawn<T: Entity<T>>(&mut self, manager: &mut IdManager, world: &mut World<T>) -> Result<PrefabInstance, DorpErr> {
+        let id = Id::new(manager);
+        let named = match self.name {
+            Some(ref name) => {
+                let name = name.clone() + "_" + &self.spawn_count.to_string();
+                match Named::new(&name, id, world) {
+                    Ok(named) => Some(named),
+                    Err(err) => {
+                        manager.free_id(id);
+                        return Err(DorpErr::DorpString("Named New: ".to_string() + &name, Box::new(err)));
+                    },
+                }
+            },
+            None => None,
+        };
+        let renderable = match self.renderable {
+            Some(ref mut template) => Some(template.take_instance(manager)),
+            None => None,
+        };
+        let transform = self.transform.clone().map(|mut transform| {
+            transform.snap();
+            transform
+        });
+        self.spawn_count += 1;
+        Ok(
+            PrefabInstance {
+                id: id,
+                transform: transform,
+                renderable: renderable,
+                named: named,
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use logic::{Id, IdManager, test_world};
+    use graphics::solid_color::{Vertex};
+    use components::{Prefab, Renderable, RenderableSolidColor, Transform};
+    use math::{Vec3};
+
+    #[test]
+    fn spawns_suffix_names_with_the_spawn_count() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut prefab = Prefab::new().with_name("crate");
+        let first = prefab.spawn(&mut manager, &mut world).unwrap();
+        let mut second = prefab.spawn(&mut manager, &mut world).unwrap();
+        assert_eq!(prefab.get_spawn_count(), 2);
+        assert!(first.get_id() != second.get_id());
+        assert_eq!(world.get_names().get("crate_0"), Some(&first.get_id()));
+        assert_eq!(world.get_names().get("crate_1"), Some(&second.get_id()));
+        assert_eq!(second.take_named().map(|named| named.get_name().to_string()), Some("crate_1".to_string()));
+        assert!(second.take_renderable().is_none());
+    }
+
+    #[test]
+    fn name_collisions_free_the_spawned_id() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let blocker = Id::new(&mut manager);
+        world.register_name(blocker, "crate_0").unwrap();
+        let probe: Id = Id::new(&mut manager);
+        manager.free_id(probe);
+        let mut prefab = Prefab::new().with_name("crate");
+        assert!(prefab.spawn(&mut manager, &mut world).is_err());
+        assert_eq!(prefab.get_spawn_count(), 0);
+        let spawned: Id = Id::from_parts(probe.get_index(), probe.get_generation() + 1);
+        assert!(!manager.is_alive(spawned));
+        assert_eq!(world.get_names().get("crate_0"), Some(&blocker));
+    }
+
+    #[test]
+    fn instances_share_buffers_and_own_their_model() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut solid_color = RenderableSolidColor::new(&mut manager);
+        solid_color.set_vertices(vec!(Vertex::new([0.0, 0.0, 0.0]), Vertex::new([1.0, 0.0, 0.0]), Vertex::new([0.0, 1.0, 0.0])));
+        solid_color.set_indices(vec!(0, 1, 2));
+        let mut renderable = Renderable::new();
+        renderable.set_solid_color(solid_color);
+        let mut transform = Transform::new();
+        transform.set_position(Vec3::from([1.0, 2.0, 3.0]));
+        let mut prefab = Prefab::new().with_renderable(renderable).with_transform(transform);
+        let (_, transform, renderable, named) = prefab.spawn(&mut manager, &mut world).unwrap().into_parts();
+        assert!(named.is_none());
+        let transform = transform.unwrap();
+        assert_eq!(transform.get_position(), Vec3::from([1.0, 2.0, 3.0]));
+        assert_eq!(transform.get_previous_position(), Vec3::from([1.0, 2.0, 3.0]));
+        let template = prefab.get_renderable().and_then(|renderable| renderable.get_solid_color()).unwrap();
+        let instance = renderable.unwrap();
+        let instance = instance.get_solid_color().unwrap();
+        assert_eq!(instance.get_vertex_id(), template.get_vertex_id());
+        assert_eq!(instance.get_index_id(), template.get_index_id());
+        assert_eq!(instance.get_view_id(), template.get_view_id());
+        assert!(instance.get_model_id() != template.get_model_id());
+        assert!(instance.get_color_id() != template.get_color_id());
+        assert_eq!(instance.get_mesh().get_indices(), &vec!(0, 1, 2));
+    }
+}
@@ -0,0 +1,105 @@
+use math::{Vec3, Mat4, Ray};
+
+#[derive(Debug, Clone)]
+pub struct Mesh {
+    positions: Vec<Vec3>,
+    indices: Vec<u32>,
+    model: Mat4,
+}
+
+impl Mesh {
+    pub fn new() -> Mesh {
+        Mesh {
+            positions: vec!(),
+            indices: vec!(),
+            model: Mat4::identity(),
+        }
+    }
+
+    pub fn set_positions(&mut self, positions: Vec<Vec3>) {
+        self.positions = positions;
+    }
+
+    pub fn set_indices(&mut self, indices: Vec<u32>) {
+        self.indices = indices;
+    }
+
+    pub fn set_model(&mut self, model: Mat4) {
+        self.model = model;
+    }
+
+    pub fn get_positions(&self) -> &Vec<Vec3> {
+        &self.positions
+    }
+
+    pub fn get_indices(&self) -> &Vec<u32> {
+        &self.indices
+    }
+
+    pub fn get_model(&self) -> Mat4 {
+        self.model
+    }
+
+    pub fn intersect(&self, ray: Ray) -> Option<f32> {
+        self.intersect_with_model(ray, self.model)
+    }
+
+    pub fn intersect_with_model(&self, ray: Ray, model: Mat4) -> Option<f32> {
+        let model = model.to_transpose();
+        let mut nearest: Option<f32> = None;
+        'triangles: for triangle in self.indices.chunks(3) {
+            if triangle.len() < 3 {
+                break;
+            }
+            let mut corners = [Vec3::zero(); 3];
+            for i in 0..3 {
+                match self.positions.get(triangle[i] as usize) {
+                    Some(position) => corners[i] = Vec3::from(model * position.to_vec4(1.0)),
+                    None => continue 'triangles,
+                }
+            }
+            if let Some(distance) = ray.intersect_triangle(corners[0], corners[1], corners[2]) {
+                nearest = match nearest {
+                    Some(other) if other <= distance => Some(other),
+                    _ => Some(distance),
+                };
+            }
+        }
+        nearest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use math::{Vec3, Mat4, Ray};
+    use components::renderables::{Mesh};
+
+    fn quad() -> Mesh {
+        let mut mesh = Mesh::new();
+        mesh.set_positions(vec!(
+            Vec3::from([-1.0, -1.0, 0.0]),
+            Vec3::from([1.0, -1.0, 0.0]),
+            Vec3::from([1.0, 1.0, 0.0]),
+            Vec3::from([-1.0, 1.0, 0.0]),
+        ));
+        mesh.set_indices(vec!(0, 1, 2, 0, 2, 3));
+        mesh
+    }
+
+    #[test]
+    fn intersect_uses_model() {
+        let mesh = quad();
+        let model = Mat4::translation_from_vec3(Vec3::from([10.0, 0.0, 0.0])).to_transpose();
+        let ray = Ray::new(Vec3::from([10.0, 0.0, 5.0]), Vec3::from([0.0, 0.0, -1.0]));
+        assert_eq!(mesh.intersect(ray), None);
+        assert_eq!(mesh.intersect_with_model(ray, model), Some(5.0));
+    }
+
+    #[test]
+    fn intersect_skips_bad_triangles() {
+        let mut mesh = quad();
+        mesh.set_indices(vec!(0, 1, 9, 0, 2, 3));
+        let ray = Ray::new(Vec3::from([-0.5, 0.5, 5.0]), Vec3::from([0.0, 0.0, -1.0]));
+        assert_eq!(mesh.intersect(ray), Some(5.0));
+    }
+}
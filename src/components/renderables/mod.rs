@@ -2,8 +2,10 @@ mod texture2d;
 mod vertex_color;
 mod solid_color;
 mod renderable;
+mod mesh;
 
 pub use self::renderable::{Renderable};
+pub use self::mesh::{Mesh};
 pub use self::texture2d::{RenderableTex2};
 pub use self::solid_color::{RenderableSolidColor};
 pub use self::vertex_color::{RenderableVertexColor};
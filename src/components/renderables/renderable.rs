@@ -1,6 +1,7 @@
+use logic::{IdManager};
 use graphics::{RendererType, Window, SyncData, Renderers};
-use components::renderables::{RenderableTex2, RenderableVertexColor, RenderableSolidColor};
-use math::{Mat4};
+use components::renderables::{RenderableTex2, RenderableVertexColor, RenderableSolidColor, Mesh};
+use math::{Mat4, Ray};
 use err::DorpErr;
 
 #[derive(Debug, Clone)]
@@ -49,6 +50,25 @@ impl Renderable {
         Ok(())
     }
 
+    pub fn new_instance(&self, manager: &mut IdManager) -> Renderable {
+        Renderable {
+            renderer_type: self.renderer_type,
+            texture2d: self.texture2d.as_ref().map(|texture2d| texture2d.new_instance(manager)),
+            vertex_color: self.vertex_color.as_ref().map(|vertex_color| vertex_color.new_instance(manager)),
+            solid_color: self.solid_color.as_ref().map(|solid_color| solid_color.new_instance(manager)),
+        }
+    }
+
+    // Like new_instance, but the instance also takes over whatever shared buffers this one hasn't uploaded yet
+    pub fn take_instance(&mut self, manager: &mut IdManager) -> Renderable {
+        Renderable {
+            renderer_type: self.renderer_type,
+            texture2d: self.texture2d.as_mut().map(|texture2d| texture2d.take_instance(manager)),
+            vertex_color: self.vertex_color.as_mut().map(|vertex_color| vertex_color.take_instance(manager)),
+            solid_color: self.solid_color.as_mut().map(|solid_color| solid_color.take_instance(manager)),
+        }
+    }
+
     pub fn set_model(&mut self, matrix: Mat4) -> Result<(), DorpErr> {
         match self.get_renderer_type() {
             RendererType::Texture2d => match self.get_mut_texture2d() {
@@ -69,6 +89,30 @@ impl Renderable {
     }
 
 
+    pub fn get_mesh(&self) -> Option<&Mesh> {
+        match self.get_renderer_type() {
+            RendererType::Texture2d => self.get_texture2d().map(|tex2| tex2.get_mesh()),
+            RendererType::SolidColor => self.get_solid_color().map(|solid_color| solid_color.get_mesh()),
+            RendererType::VertexColor => self.get_vertex_color().map(|vertex_color| vertex_color.get_mesh()),
+            RendererType::Empty => None,
+        }
+    }
+
+    pub fn intersect(&self, ray: Ray) -> Option<f32> {
+        match self.get_mesh() {
+            Some(mesh) => mesh.intersect(ray),
+            None => None,
+        }
+    }
+
+    pub fn intersect_with_model(&self, ray: Ray, model: Mat4) -> Option<f32> {
+        match self.get_mesh() {
+            Some(mesh) => mesh.intersect_with_model(ray, model),
+            None => None,
+        }
+    }
+
+
     pub fn set_texture2d(&mut self, texture2d: RenderableTex2) {
         self.texture2d = Some(texture2d);
         self.renderer_type = RendererType::Texture2d;
@@ -1,7 +1,8 @@
-use logic::{Id, IdManager, IdType};
+use logic::{IdManager, VertexId, IndexId, DrawMethodId, MatrixId, ColorId};
 use graphics::{Window, SyncData, Renderers};
 use graphics::solid_color::{Vertex, Index, DrawMethod};
-use math::{Mat4, Vec4};
+use math::{Mat4, Vec3, Vec4};
+use components::renderables::{Mesh};
 use err::DorpErr;
 
 #[derive(Debug, Clone)]
@@ -46,27 +47,31 @@ impl Changes {
 
 #[derive(Debug, Clone)]
 pub struct RenderableSolidColor {
-    vertex_id: Id,
-    index_id: Id,
-    draw_method_id: Id,
-    perspective_id: Id,
-    view_id: Id,
-    model_id: Id,
-    color_id: Id,
+    vertex_id: VertexId,
+    index_id: IndexId,
+    draw_method_id: DrawMethodId,
+    perspective_id: MatrixId,
+    view_id: MatrixId,
+    model_id: MatrixId,
+    color_id: ColorId,
+    color: Option<Vec4>,
     changes: Changes,
+    mesh: Mesh,
 }
 
 impl RenderableSolidColor {
     pub fn new(manager: &mut IdManager) -> RenderableSolidColor {
         RenderableSolidColor {
-            vertex_id: Id::new(manager, IdType::Vertex),
-            index_id: Id::new(manager, IdType::Index),
-            draw_method_id: Id::new(manager, IdType::DrawMethod),
-            perspective_id: Id::new(manager, IdType::Matrix),
-            view_id: Id::new(manager, IdType::Matrix),
-            model_id: Id::new(manager, IdType::Matrix),
-            color_id: Id::new(manager, IdType::Color),
+            vertex_id: VertexId::new(manager),
+            index_id: IndexId::new(manager),
+            draw_method_id: DrawMethodId::new(manager),
+            perspective_id: MatrixId::new(manager),
+            view_id: MatrixId::new(manager),
+            model_id: MatrixId::new(manager),
+            color_id: ColorId::new(manager),
+            color: None,
             changes: Changes::new(),
+            mesh: Mesh::new(),
         }
     }
 
@@ -118,12 +123,44 @@ impl RenderableSolidColor {
         Ok(())
     }
 
+    pub fn new_instance(&self, manager: &mut IdManager) -> RenderableSolidColor {
+        let mut instance = self.clone();
+        instance.model_id = MatrixId::new(manager);
+        instance.color_id = ColorId::new(manager);
+        instance.clear_shared_changes();
+        let model = instance.mesh.get_model();
+        instance.changes.model = Some((model, model.to_inverse()));
+        instance.changes.color = instance.color;
+        instance.changes.dirty_render = true;
+        instance
+    }
+
+    pub fn take_instance(&mut self, manager: &mut IdManager) -> RenderableSolidColor {
+        let mut instance = self.new_instance(manager);
+        instance.changes.vertices = self.changes.vertices.take();
+        instance.changes.indices = self.changes.indices.take();
+        instance.changes.draw_method = self.changes.draw_method.take();
+        instance.changes.perspective = self.changes.perspective.take();
+        instance.changes.view = self.changes.view.take();
+        instance
+    }
+
+    fn clear_shared_changes(&mut self) {
+        self.changes.vertices = None;
+        self.changes.indices = None;
+        self.changes.draw_method = None;
+        self.changes.perspective = None;
+        self.changes.view = None;
+    }
+
     pub fn set_vertices(&mut self, vertices: Vec<Vertex>) {
+        self.mesh.set_positions(vertices.iter().map(|vertex| Vec3::from(vertex.get_position())).collect());
         self.changes.vertices = Some(vertices);
         self.changes.dirty_render = true;
     }
 
     pub fn set_indices(&mut self, indices: Vec<Index>) {
+        self.mesh.set_indices(indices.clone());
         self.changes.indices = Some(indices);
         self.changes.dirty_render = true;
     }
@@ -144,68 +181,74 @@ impl RenderableSolidColor {
     }
 
     pub fn set_model(&mut self, matrix: Mat4) {
+        self.mesh.set_model(matrix);
         self.changes.model = Some((matrix, matrix.to_inverse()));
         self.changes.dirty_render = true;
     }
 
     pub fn set_color(&mut self, color: Vec4) {
+        self.color = Some(color);
         self.changes.color = Some(color);
         self.changes.dirty_render = true;
     }
 
-    pub fn set_vertex_id(&mut self, id: Id) {
+    pub fn set_vertex_id(&mut self, id: VertexId) {
         self.vertex_id = id;
     }
 
-    pub fn set_index_id(&mut self, id: Id) {
+    pub fn set_index_id(&mut self, id: IndexId) {
         self.index_id = id;
     }
 
-    pub fn set_draw_method_id(&mut self, id: Id) {
+    pub fn set_draw_method_id(&mut self, id: DrawMethodId) {
         self.draw_method_id = id;
     }
 
-    pub fn set_perspective_id(&mut self, id: Id) {
+    pub fn set_perspective_id(&mut self, id: MatrixId) {
         self.perspective_id = id;
     }
 
-    pub fn set_view_id(&mut self, id: Id) {
+    pub fn set_view_id(&mut self, id: MatrixId) {
         self.view_id = id;
     }
 
-    pub fn set_model_id(&mut self, id: Id) {
+    pub fn set_model_id(&mut self, id: MatrixId) {
         self.model_id = id;
     }
 
-    pub fn set_color_id(&mut self, id: Id) {
+    pub fn set_color_id(&mut self, id: ColorId) {
         self.color_id = id;
     }
 
-    pub fn get_vertex_id(&self) -> Id {
+    pub fn get_vertex_id(&self) -> VertexId {
         self.vertex_id
     }
 
-    pub fn get_index_id(&self) -> Id {
+    pub fn get_index_id(&self) -> IndexId {
         self.index_id
     }
 
-    pub fn get_draw_method_id(&self) -> Id {
+    pub fn get_draw_method_id(&self) -> DrawMethodId {
         self.draw_method_id
     }
 
-    pub fn get_perspective_id(&self) -> Id {
+    pub fn get_perspective_id(&self) -> MatrixId {
         self.perspective_id
     }
 
-    pub fn get_view_id(&self) -> Id {
+    pub fn get_view_id(&self) -> MatrixId {
         self.view_id
     }
 
-    pub fn get_model_id(&self) -> Id {
+    pub fn get_model_id(&self) -> MatrixId {
         self.model_id
     }
 
-    pub fn get_color_id(&self) -> Id {
+    pub fn get_color_id(&self) -> ColorId {
         self.color_id
     }
+
+    pub fn get_mesh(&self) -> &Mesh {
+        &self.mesh
+    }
 }
@@ -1,14 +1,17 @@
-use logic::{Id, IdManager, IdType};
-use math::{Mat4};
+use std::sync::{Arc};
+
+use logic::{IdManager, VertexId, IndexId, TextureId, DrawMethodId, MatrixId};
+use math::{Mat4, Vec3};
 use graphics::{Window, SyncData, Renderers};
 use graphics::texture2d::{Vertex, Index, DrawMethod};
+use components::renderables::{Mesh};
 use err::DorpErr;
 
 #[derive(Debug, Clone)]
 struct Changes {
     vertices: Option<Vec<Vertex>>,
     indices: Option<Vec<Index>>,
-    texture: Option<&'static [u8]>,
+    texture: Option<Arc<Vec<u8>>>,
     draw_method: Option<DrawMethod>,
     perspective: Option<(Mat4, Mat4)>,
     view: Option<(Mat4, Mat4)>,
@@ -33,27 +36,29 @@ impl Changes {
 
 #[derive(Debug, Clone)]
 pub struct RenderableTex2 {
-    vertex_id: Id,
-    index_id: Id,
-    texture_id: Id,
-    draw_method_id: Id,
-    perspective_id: Id,
-    view_id: Id,
-    model_id: Id,
+    vertex_id: VertexId,
+    index_id: IndexId,
+    texture_id: TextureId,
+    draw_method_id: DrawMethodId,
+    perspective_id: MatrixId,
+    view_id: MatrixId,
+    model_id: MatrixId,
     changes: Changes,
+    mesh: Mesh,
 }
 
 impl RenderableTex2 {
     pub fn new(manager: &mut IdManager) -> RenderableTex2 {
         RenderableTex2 {
-            vertex_id: Id::new(manager, IdType::Vertex),
-            index_id: Id::new(manager, IdType::Index),
-            texture_id: Id::new(manager, IdType::Texture),
-            draw_method_id: Id::new(manager, IdType::DrawMethod),
-            perspective_id: Id::new(manager, IdType::Matrix),
-            view_id: Id::new(manager, IdType::Matrix),
-            model_id: Id::new(manager, IdType::Matrix),
+            vertex_id: VertexId::new(manager),
+            index_id: IndexId::new(manager),
+            texture_id: TextureId::new(manager),
+            draw_method_id: DrawMethodId::new(manager),
+            perspective_id: MatrixId::new(manager),
+            view_id: MatrixId::new(manager),
+            model_id: MatrixId::new(manager),
             changes: Changes::new(),
+            mesh: Mesh::new(),
         }
     }
 
@@ -77,9 +82,9 @@ impl RenderableTex2 {
                 },
                 None => (),
             }
-            match self.changes.texture {
+            match self.changes.texture.clone() {
                 Some(texture) => {
-                    match renderers.get_mut_texture2d().set_texture(self.texture_id, window, texture) {
+                    match renderers.get_mut_texture2d().set_texture(self.texture_id, window, &texture) {
                         Ok(()) => (),
                         Err(err) => return Err(DorpErr::Dorp("Renderers Get Mut Texture2d Set Texture", Box::new(err))),
                     }
@@ -122,18 +127,54 @@ impl RenderableTex2 {
         Ok(())
     }
 
+    pub fn new_instance(&self, manager: &mut IdManager) -> RenderableTex2 {
+        let mut instance = self.clone();
+        instance.model_id = MatrixId::new(manager);
+        instance.clear_shared_changes();
+        let model = instance.mesh.get_model();
+        instance.changes.model = Some((model, model.to_inverse()));
+        instance.changes.dirty_render = true;
+        instance
+    }
+
+    pub fn take_instance(&mut self, manager: &mut IdManager) -> RenderableTex2 {
+        let mut instance = self.new_instance(manager);
+        instance.changes.vertices = self.changes.vertices.take();
+        instance.changes.indices = self.changes.indices.take();
+        instance.changes.texture = self.changes.texture.take();
+        instance.changes.draw_method = self.changes.draw_method.take();
+        instance.changes.perspective = self.changes.perspective.take();
+        instance.changes.view = self.changes.view.take();
+        instance
+    }
+
+    fn clear_shared_changes(&mut self) {
+        self.changes.vertices = None;
+        self.changes.indices = None;
+        self.changes.texture = None;
+        self.changes.draw_method = None;
+        self.changes.perspective = None;
+        self.changes.view = None;
+    }
+
     pub fn set_vertices(&mut self, vertices: Vec<Vertex>) {
+        self.mesh.set_positions(vertices.iter().map(|vertex| Vec3::from(vertex.get_position())).collect());
         self.changes.vertices = Some(vertices);
         self.changes.dirty_render = true;
     }
 
     pub fn set_indices(&mut self, indices: Vec<Index>) {
+        self.mesh.set_indices(indices.clone());
         self.changes.indices = Some(indices);
         self.changes.dirty_render = true;
     }
 
     pub fn set_texture(&mut self, texture: &'static [u8]) {
-        self.changes.texture = Some(texture);
+        self.set_texture_data(texture.to_vec());
+    }
+
+    pub fn set_texture_data(&mut self, texture: Vec<u8>) {
+        self.changes.texture = Some(Arc::new(texture));
         self.changes.dirty_render = true;
     }
 
@@ -153,63 +194,68 @@ impl RenderableTex2 {
     }
 
     pub fn set_model(&mut self, matrix: Mat4) {
+        self.mesh.set_model(matrix);
         self.changes.model = Some((matrix, matrix.to_inverse()));
         self.changes.dirty_render = true;
     }
 
-    pub fn set_vertex_id(&mut self, id: Id) {
+    pub fn set_vertex_id(&mut self, id: VertexId) {
         self.vertex_id = id;
     }
 
-    pub fn set_index_id(&mut self, id: Id) {
+    pub fn set_index_id(&mut self, id: IndexId) {
         self.index_id = id;
     }
 
-    pub fn set_texture_id(&mut self, id: Id) {
+    pub fn set_texture_id(&mut self, id: TextureId) {
         self.texture_id = id;
     }
 
-    pub fn set_draw_method_id(&mut self, id: Id) {
+    pub fn set_draw_method_id(&mut self, id: DrawMethodId) {
         self.draw_method_id = id;
     }
 
-    pub fn set_perspective_id(&mut self, id: Id) {
+    pub fn set_perspective_id(&mut self, id: MatrixId) {
         self.perspective_id = id;
     }
 
-    pub fn set_view_id(&mut self, id: Id) {
+    pub fn set_view_id(&mut self, id: MatrixId) {
         self.view_id = id;
     }
 
-    pub fn set_model_id(&mut self, id: Id) {
+    pub fn set_model_id(&mut self, id: MatrixId) {
         self.model_id = id;
     }
 
-    pub fn get_vertex_id(&self) -> Id {
+    pub fn get_vertex_id(&self) -> VertexId {
         self.vertex_id
     }
 
-    pub fn get_index_id(&self) -> Id {
+    pub fn get_index_id(&self) -> IndexId {
         self.index_id
     }
 
-    pub fn get_texture_id(&self) -> Id {
+    pub fn get_texture_id(&self) -> TextureId {
         self.texture_id
     }
 
-    pub fn get_draw_method_id(&self) -> Id {
+    pub fn get_draw_method_id(&self) -> DrawMethodId {
         self.draw_method_id
     }
 
-    pub fn get_perspective_id(&self) -> Id {
+    pub fn get_perspective_id(&self) -> MatrixId {
         self.perspective_id
     }
 
-    pub fn get_view_id(&self) -> Id {
+    pub fn get_view_id(&self) -> MatrixId {
         self.view_id
     }
 
-    pub fn get_model_id(&self) -> Id {
+    pub fn get_model_id(&self) -> MatrixId {
         self.model_id
     }
+
+    pub fn get_mesh(&self) -> &Mesh {
+        &self.mesh
+    }
 }
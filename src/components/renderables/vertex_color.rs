@@ -1,7 +1,8 @@
-use logic::{Id, IdManager, IdType};
+use logic::{IdManager, VertexId, IndexId, DrawMethodId, MatrixId};
 use graphics::{Window, SyncData, Renderers};
 use graphics::vertex_color::{Vertex, Index, DrawMethod};
-use math::{Mat4};
+use math::{Mat4, Vec3};
+use components::renderables::{Mesh};
 use err::DorpErr;
 
 #[derive(Debug, Clone)]
@@ -31,25 +32,27 @@ impl Changes {
 
 #[derive(Debug, Clone)]
 pub struct RenderableVertexColor {
-    vertex_id: Id,
-    index_id: Id,
-    draw_method_id: Id,
-    perspective_id: Id,
-    view_id: Id,
-    model_id: Id,
+    vertex_id: VertexId,
+    index_id: IndexId,
+    draw_method_id: DrawMethodId,
+    perspective_id: MatrixId,
+    view_id: MatrixId,
+    model_id: MatrixId,
     changes: Changes,
+    mesh: Mesh,
 }
 
 impl RenderableVertexColor {
     pub fn new(manager: &mut IdManager) -> RenderableVertexColor {
         RenderableVertexColor {
-            vertex_id: Id::new(manager, IdType::Vertex),
-            index_id: Id::new(manager, IdType::Index),
-            draw_method_id: Id::new(manager, IdType::DrawMethod),
-            perspective_id: Id::new(manager, IdType::Matrix),
-            view_id: Id::new(manager, IdType::Matrix),
-            model_id: Id::new(manager, IdType::Matrix),
+            vertex_id: VertexId::new(manager),
+            index_id: IndexId::new(manager),
+            draw_method_id: DrawMethodId::new(manager),
+            perspective_id: MatrixId::new(manager),
+            view_id: MatrixId::new(manager),
+            model_id: MatrixId::new(manager),
             changes: Changes::new(),
+            mesh: Mesh::new(),
         }
     }
 
@@ -96,12 +99,42 @@ impl RenderableVertexColor {
         Ok(())
     }
 
+    pub fn new_instance(&self, manager: &mut IdManager) -> RenderableVertexColor {
+        let mut instance = self.clone();
+        instance.model_id = MatrixId::new(manager);
+        instance.clear_shared_changes();
+        let model = instance.mesh.get_model();
+        instance.changes.model = Some((model, model.to_inverse()));
+        instance.changes.dirty_render = true;
+        instance
+    }
+
+    pub fn take_instance(&mut self, manager: &mut IdManager) -> RenderableVertexColor {
+        let mut instance = self.new_instance(manager);
+        instance.changes.vertices = self.changes.vertices.take();
+        instance.changes.indices = self.changes.indices.take();
+        instance.changes.draw_method = self.changes.draw_method.take();
+        instance.changes.perspective = self.changes.perspective.take();
+        instance.changes.view = self.changes.view.take();
+        instance
+    }
+
+    fn clear_shared_changes(&mut self) {
+        self.changes.vertices = None;
+        self.changes.indices = None;
+        self.changes.draw_method = None;
+        self.changes.perspective = None;
+        self.changes.view = None;
+    }
+
     pub fn set_vertices(&mut self, vertices: Vec<Vertex>) {
+        self.mesh.set_positions(vertices.iter().map(|vertex| Vec3::from(vertex.get_position())).collect());
         self.changes.vertices = Some(vertices);
         self.changes.dirty_render = true;
     }
 
     pub fn set_indices(&mut self, indices: Vec<Index>) {
+        self.mesh.set_indices(indices.clone());
         self.changes.indices = Some(indices);
         self.changes.dirty_render = true;
     }
@@ -122,55 +155,60 @@ impl RenderableVertexColor {
     }
 
     pub fn set_model(&mut self, matrix: Mat4) {
+        self.mesh.set_model(matrix);
         self.changes.model = Some((matrix, matrix.to_inverse()));
         self.changes.dirty_render = true;
     }
 
-    pub fn set_vertex_id(&mut self, id: Id) {
+    pub fn set_vertex_id(&mut self, id: VertexId) {
         self.vertex_id = id;
     }
 
-    pub fn set_index_id(&mut self, id: Id) {
+    pub fn set_index_id(&mut self, id: IndexId) {
         self.index_id = id;
     }
 
-    pub fn set_draw_method_id(&mut self, id: Id) {
+    pub fn set_draw_method_id(&mut self, id: DrawMethodId) {
         self.draw_method_id = id;
     }
 
-    pub fn set_perspective_id(&mut self, id: Id) {
+    pub fn set_perspective_id(&mut self, id: MatrixId) {
         self.perspective_id = id;
     }
 
-    pub fn set_view_id(&mut self, id: Id) {
+    pub fn set_view_id(&mut self, id: MatrixId) {
         self.view_id = id;
     }
 
-    pub fn set_model_id(&mut self, id: Id) {
+    pub fn set_model_id(&mut self, id: MatrixId) {
         self.model_id = id;
     }
 
-    pub fn get_vertex_id(&self) -> Id {
+    pub fn get_vertex_id(&self) -> VertexId {
         self.vertex_id
     }
 
-    pub fn get_index_id(&self) -> Id {
+    pub fn get_index_id(&self) -> IndexId {
         self.index_id
     }
 
-    pub fn get_draw_method_id(&self) -> Id {
+    pub fn get_draw_method_id(&self) -> DrawMethodId {
         self.draw_method_id
     }
 
-    pub fn get_perspective_id(&self) -> Id {
+    pub fn get_perspective_id(&self) -> MatrixId {
         self.perspective_id
     }
 
-    pub fn get_view_id(&self) -> Id {
+    pub fn get_view_id(&self) -> MatrixId {
         self.view_id
     }
 
-    pub fn get_model_id(&self) -> Id {
+    pub fn get_model_id(&self) -> MatrixId {
         self.model_id
     }
+
+    pub fn get_mesh(&self) -> &Mesh {
+        &self.mesh
+    }
 }
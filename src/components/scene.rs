@@ -1,20 +1,626 @@
+use std::collections::{HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc};
+
 use logic::{IdManager, World, Entity, Id};
 use graphics::{SyncData};
+use components::{EntityDesc, EntityRegistry};
 use err::DorpErr;
 
+pub type SceneHook<T> = Box<Fn(&mut IdManager, &mut World<T>, &mut SyncData) -> Result<(), DorpErr> + Send + Sync>;
+
 pub struct Scene<T: Entity<T>> {
-    setup: Box<Fn(&mut IdManager, &mut World<T>, &mut SyncData) -> Result<(), DorpErr> + Send + Sync>,
+    setup: SceneHook<T>,
+    on_enter: Option<SceneHook<T>>,
+    on_exit: Option<SceneHook<T>>,
+    source: Option<(PathBuf, Arc<EntityRegistry<T>>)>,
 }
 
 impl<T: Entity<T>> Scene<T> {
-    pub fn new(setup: Box<Fn(&mut IdManager, &mut World<T>, &mut SyncData) -> Result<(), DorpErr> + Send + Sync>) -> Scene<T> {
+    pub fn new(setup: SceneHook<T>) -> Scene<T> {
         Scene {
             setup: setup,
+            on_enter: None,
+            on_exit: None,
+            source: None,
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P, registry: Arc<EntityRegistry<T>>) -> Result<Scene<T>, DorpErr> {
+        let path = path.as_ref().to_path_buf();
+        match EntityDesc::load_all(&path) {
+            Ok(descs) => {
+                let mut scene = Scene::from_descs(descs, registry.clone());
+                scene.source = Some((path, registry));
+                Ok(scene)
+            },
+            Err(err) => Err(DorpErr::Dorp("Entity Desc Load All", Box::new(err))),
+        }
+    }
+
+    pub fn from_descs(descs: Vec<EntityDesc>, registry: Arc<EntityRegistry<T>>) -> Scene<T> {
+        Scene::new(Box::new(move |manager, world, _| {
+            for desc in descs.iter() {
+                match registry.create(desc, manager, world) {
+                    Ok(entity) => world.add_entity(entity),
+                    Err(err) => return Err(DorpErr::Dorp("Entity Registry Create", Box::new(err))),
+                }
+            }
+            Ok(())
+        }))
+    }
+
+    pub fn with_on_enter(mut self, on_enter: SceneHook<T>) -> Scene<T> {
+        self.on_enter = Some(on_enter);
+        self
+    }
+
+    pub fn with_on_exit(mut self, on_exit: SceneHook<T>) -> Scene<T> {
+        self.on_exit = Some(on_exit);
+        self
+    }
+
+    pub fn get_source_path(&self) -> Option<&Path> {
+        match self.source {
+            Some((ref path, _)) => Some(path),
+            None => None,
+        }
+    }
+
+    pub fn reload(&mut self) -> Result<(), DorpErr> {
+        match self.load_source() {
+            Ok(scene) => {
+                self.setup = scene.setup;
+                Ok(())
+            },
+            Err(err) => Err(DorpErr::Dorp("Self Load Source", Box::new(err))),
         }
     }
 
+    fn load_source(&self) -> Result<Scene<T>, DorpErr> {
+        match self.source {
+            Some((ref path, ref registry)) => Scene::from_file(path, registry.clone()),
+            None => Err(DorpErr::Base("Scene was not loaded from a file")),
+        }
+    }
+
+    pub fn instantiate(&self, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<Vec<Id>, DorpErr> {
+        let existing: HashSet<Id> = world.get_entities().keys().cloned().collect();
+        match (self.setup)(manager, world, sync_data) {
+            Ok(()) => (),
+            Err(err) => return Err(DorpErr::Dorp("Scene Setup", Box::new(err))),
+        }
+        Ok(world.get_entities().keys().filter(|id| !existing.contains(id)).cloned().collect())
+    }
+
     pub fn tick_mut(&mut self, my_id: Id, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<(), DorpErr> {
         world.queue_remove_entity(my_id);
         (self.setup)(manager, world, sync_data)
     }
+
+    fn enter(&self, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<(), DorpErr> {
+        match self.on_enter {
+            Some(ref on_enter) => on_enter(manager, world, sync_data),
+            None => Ok(()),
+        }
+    }
+
+    fn exit(&self, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<(), DorpErr> {
+        match self.on_exit {
+            Some(ref on_exit) => on_exit(manager, world, sync_data),
+            None => Ok(()),
+        }
+    }
+}
+
+pub enum SceneTransition<T: Entity<T>> {
+    Push(Scene<T>),
+    Pop,
+    Replace(Scene<T>),
+}
+
+struct ActiveScene<T: Entity<T>> {
+    scene: Scene<T>,
+    entities: Vec<Id>,
+}
+
+pub struct SceneManager<T: Entity<T>> {
+    stack: Vec<ActiveScene<T>>,
+    pending: Vec<SceneTransition<T>>,
+}
+
+impl<T: Entity<T>> SceneManager<T> {
+    pub fn new() -> SceneManager<T> {
+        SceneManager {
+            stack: vec!(),
+            pending: vec!(),
+        }
+    }
+
+    pub fn push(&mut self, scene: Scene<T>) {
+        self.pending.push(SceneTransition::Push(scene));
+    }
+
+    pub fn pop(&mut self) {
+        self.pending.push(SceneTransition::Pop);
+    }
+
+    pub fn replace(&mut self, scene: Scene<T>) {
+        self.pending.push(SceneTransition::Replace(scene));
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    pub fn get_depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    pub fn get_top_entities(&self) -> Option<&Vec<Id>> {
+        self.stack.last().map(|active| &active.entities)
+    }
+
+    pub fn is_owned_by_top(&self, id: Id) -> bool {
+        match self.stack.last() {
+            Some(active) => active.entities.contains(&id),
+            None => false,
+        }
+    }
+
+    pub fn adopt(&mut self, id: Id) -> Result<(), DorpErr> {
+        match self.stack.last_mut() {
+            Some(active) => {
+                active.entities.push(id);
+                Ok(())
+            },
+            None => Err(DorpErr::Base("Scene stack was empty")),
+        }
+    }
+
+    // Loads the file for every matching entry before any of them is touched, so a bad file leaves the whole stack alone
+    pub fn reload_file(&mut self, path: &Path, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<bool, DorpErr> {
+        let mut fresh_scenes = vec!();
+        for active in self.stack.iter() {
+            if active.scene.get_source_path() != Some(path) {
+                fresh_scenes.push(None);
+                continue;
+            }
+            match active.scene.load_source() {
+                Ok(fresh) => fresh_scenes.push(Some(fresh)),
+                Err(err) => return Err(DorpErr::Dorp("Scene Load Source", Box::new(err))),
+            }
+        }
+        let mut reloaded = false;
+        for (active, fresh) in self.stack.iter_mut().zip(fresh_scenes.into_iter()) {
+            let fresh = match fresh {
+                Some(fresh) => fresh,
+                None => continue,
+            };
+            let old_names = match SceneManager::find_names(&active.entities, world) {
+                Ok(old_names) => old_names,
+                Err(err) => return Err(DorpErr::Dorp("Scene Manager Find Names", Box::new(err))),
+            };
+            for &(ref name, _) in old_names.iter() {
+                match world.deregister_name(name) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::DorpString("World Deregister Name: ".to_string() + name, Box::new(err))),
+                }
+            }
+            let existing: HashSet<Id> = world.get_entities().keys().cloned().collect();
+            match fresh.instantiate(manager, world, sync_data) {
+                Ok(entities) => {
+                    for id in active.entities.iter() {
+                        world.queue_remove_entity(*id);
+                    }
+                    active.entities = entities;
+                    active.scene.setup = fresh.setup;
+                },
+                Err(err) => {
+                    match SceneManager::despawn_new(&existing, world) {
+                        Ok(()) => (),
+                        Err(err) => return Err(DorpErr::Dorp("Scene Manager Despawn New", Box::new(err))),
+                    }
+                    for (name, id) in old_names {
+                        world.restore_name(name, id);
+                    }
+                    return Err(DorpErr::Dorp("Scene Instantiate", Box::new(err)));
+                },
+            }
+            reloaded = true;
+        }
+        Ok(reloaded)
+    }
+
+    // A failed transition returns its error and leaves the transitions queued after it for the next call
+    pub fn tick_mut(&mut self, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<(), DorpErr> {
+        while !self.pending.is_empty() {
+            match self.pending.remove(0) {
+                SceneTransition::Push(scene) => match self.enter(scene, manager, world, sync_data) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Self Enter", Box::new(err))),
+                },
+                SceneTransition::Pop => match self.exit(manager, world, sync_data) {
+                    Ok(_) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Self Exit", Box::new(err))),
+                },
+                SceneTransition::Replace(scene) => match self.replace_top(scene, manager, world, sync_data) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Self Replace Top", Box::new(err))),
+                },
+            }
+        }
+        Ok(())
+    }
+
+    // Builds the new scene next to the old one, the old one only exits and despawns once the new one is in place
+    fn replace_top(&mut self, scene: Scene<T>, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<(), DorpErr> {
+        let old_names = match self.stack.last() {
+            Some(active) => match SceneManager::find_names(&active.entities, world) {
+                Ok(old_names) => old_names,
+                Err(err) => return Err(DorpErr::Dorp("Scene Manager Find Names", Box::new(err))),
+            },
+            None => return Err(DorpErr::Base("Scene stack was empty")),
+        };
+        for &(ref name, _) in old_names.iter() {
+            match world.deregister_name(name) {
+                Ok(()) => (),
+                Err(err) => return Err(DorpErr::DorpString("World Deregister Name: ".to_string() + name, Box::new(err))),
+            }
+        }
+        let existing: HashSet<Id> = world.get_entities().keys().cloned().collect();
+        let entities = match scene.instantiate(manager, world, sync_data) {
+            Ok(entities) => entities,
+            Err(err) => return SceneManager::undo_replace(DorpErr::Dorp("Scene Instantiate", Box::new(err)), &existing, old_names, world),
+        };
+        let exited = match self.stack.last() {
+            Some(active) => active.scene.exit(manager, world, sync_data),
+            None => Err(DorpErr::Base("Scene stack was empty")),
+        };
+        match exited {
+            Ok(()) => (),
+            Err(err) => return SceneManager::undo_replace(DorpErr::Dorp("Scene Exit", Box::new(err)), &existing, old_names, world),
+        }
+        match scene.enter(manager, world, sync_data) {
+            Ok(()) => (),
+            Err(err) => {
+                let err = DorpErr::Dorp("Scene Enter", Box::new(err));
+                // The old scene already exited, run its on_enter again since it stays on top
+                let reentered = match self.stack.last() {
+                    Some(active) => active.scene.enter(manager, world, sync_data),
+                    None => Ok(()),
+                };
+                let err = match reentered {
+                    Ok(()) => err,
+                    Err(enter_err) => DorpErr::DorpString(format!("Scene Enter Old Scene after {:?}", err), Box::new(enter_err)),
+                };
+                return SceneManager::undo_replace(err, &existing, old_names, world);
+            },
+        }
+        let old = match self.stack.pop() {
+            Some(old) => old,
+            None => return Err(DorpErr::Base("Scene stack was empty")),
+        };
+        for id in old.entities.iter() {
+            if world.is_entity_alive(*id) {
+                world.queue_remove_entity(*id);
+            }
+        }
+        self.stack.push(ActiveScene {
+            scene: scene,
+            entities: entities,
+        });
+        Ok(())
+    }
+
+    fn undo_replace(err: DorpErr, existing: &HashSet<Id>, old_names: Vec<(String, Id)>, world: &mut World<T>) -> Result<(), DorpErr> {
+        let despawned = SceneManager::despawn_new(existing, world);
+        for (name, id) in old_names {
+            world.restore_name(name, id);
+        }
+        match despawned {
+            Ok(()) => Err(err),
+            Err(despawn_err) => Err(DorpErr::DorpString(format!("Scene Manager Despawn New after {:?}", err), Box::new(despawn_err))),
+        }
+    }
+
+    pub fn clear(&mut self, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<(), DorpErr> {
+        self.pending.clear();
+        while !self.stack.is_empty() {
+            match self.exit(manager, world, sync_data) {
+                Ok(_) => (),
+                Err(err) => return Err(DorpErr::Dorp("Self Exit", Box::new(err))),
+            }
+        }
+        Ok(())
+    }
+
+    fn enter(&mut self, scene: Scene<T>, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<(), DorpErr> {
+        let existing: HashSet<Id> = world.get_entities().keys().cloned().collect();
+        let result = match scene.instantiate(manager, world, sync_data) {
+            Ok(entities) => match scene.enter(manager, world, sync_data) {
+                Ok(()) => Ok(entities),
+                Err(err) => Err(DorpErr::Dorp("Scene Enter", Box::new(err))),
+            },
+            Err(err) => Err(DorpErr::Dorp("Scene Instantiate", Box::new(err))),
+        };
+        match result {
+            Ok(entities) => {
+                self.stack.push(ActiveScene {
+                    scene: scene,
+                    entities: entities,
+                });
+                Ok(())
+            },
+            Err(err) => match SceneManager::despawn_new(&existing, world) {
+                Ok(()) => Err(err),
+                Err(despawn_err) => Err(DorpErr::DorpString(format!("Scene Manager Despawn New after {:?}", err), Box::new(despawn_err))),
+            },
+        }
+    }
+
+    fn exit(&mut self, manager: &mut IdManager, world: &mut World<T>, sync_data: &mut SyncData) -> Result<Scene<T>, DorpErr> {
+        match self.stack.last() {
+            Some(active) => {
+                match active.scene.exit(manager, world, sync_data) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Scene Exit", Box::new(err))),
+                }
+                match SceneManager::despawn(&active.entities, world) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Scene Manager Despawn", Box::new(err))),
+                }
+            },
+            None => return Err(DorpErr::Base("Scene stack was empty")),
+        }
+        match self.stack.pop() {
+            Some(active) => Ok(active.scene),
+            None => Err(DorpErr::Base("Scene stack was empty")),
+        }
+    }
+
+    // Checks every name before anything is deregistered so a bad entry leaves the table untouched
+    fn find_names(entities: &[Id], world: &World<T>) -> Result<Vec<(String, Id)>, DorpErr> {
+        let mut names = vec!();
+        for id in entities.iter() {
+            let name = match world.get_entity_by_id(*id) {
+                Some(entity) => match entity.get_named() {
+                    Some(named) => named.get_name().to_string(),
+                    None => continue,
+                },
+                None => continue,
+            };
+            if world.get_names().get(&name) != Some(id) {
+                return Err(DorpErr::BaseString("Name is not registered to its entity: ".to_string() + &name));
+            }
+            names.push((name, *id));
+        }
+        Ok(names)
+    }
+
+    // Validates every name first so a failed despawn leaves the entities and names in place
+    fn despawn(entities: &[Id], world: &mut World<T>) -> Result<(), DorpErr> {
+        let names = match SceneManager::find_names(entities, world) {
+            Ok(names) => names,
+            Err(err) => return Err(DorpErr::Dorp("Scene Manager Find Names", Box::new(err))),
+        };
+        for (name, _) in names {
+            match world.deregister_name(&name) {
+                Ok(()) => (),
+                Err(err) => return Err(DorpErr::DorpString("World Deregister Name: ".to_string() + &name, Box::new(err))),
+            }
+        }
+        for id in entities.iter() {
+            if world.is_entity_alive(*id) {
+                world.queue_remove_entity(*id);
+            }
+        }
+        Ok(())
+    }
+
+    fn despawn_new(existing: &HashSet<Id>, world: &mut World<T>) -> Result<(), DorpErr> {
+        let partial: Vec<Id> = world.get_entities().keys().filter(|id| !existing.contains(id)).cloned().collect();
+        SceneManager::despawn(&partial, world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use logic::{World, Id, IdManager, TestEntity, test_world};
+    use graphics::{SyncData};
+    use components::{Scene, SceneManager, Named};
+    use err::DorpErr;
+
+    fn spawn(name: Option<&str>, manager: &mut IdManager, world: &mut World<TestEntity>) -> Result<(), DorpErr> {
+        let id = Id::new(manager);
+        let mut entity = TestEntity::new(id);
+        if let Some(name) = name {
+            match Named::new(name, id, world) {
+                Ok(named) => entity = entity.with_named(named),
+                Err(err) => return Err(DorpErr::Dorp("Named New", Box::new(err))),
+            }
+        }
+        world.add_entity(entity);
+        Ok(())
+    }
+
+    fn spawn_menu(manager: &mut IdManager, world: &mut World<TestEntity>, _: &mut SyncData) -> Result<(), DorpErr> {
+        match spawn(Some("menu"), manager, world) {
+            Ok(()) => spawn(None, manager, world),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn spawn_level(manager: &mut IdManager, world: &mut World<TestEntity>, _: &mut SyncData) -> Result<(), DorpErr> {
+        spawn(Some("player"), manager, world)
+    }
+
+    fn spawn_broken(manager: &mut IdManager, world: &mut World<TestEntity>, _: &mut SyncData) -> Result<(), DorpErr> {
+        match spawn(Some("broken"), manager, world) {
+            Ok(()) => Err(DorpErr::Base("Broken scene")),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn mark_exit(manager: &mut IdManager, world: &mut World<TestEntity>, _: &mut SyncData) -> Result<(), DorpErr> {
+        spawn(Some("exited"), manager, world)
+    }
+
+    fn fail(_: &mut IdManager, _: &mut World<TestEntity>, _: &mut SyncData) -> Result<(), DorpErr> {
+        Err(DorpErr::Base("Failing hook"))
+    }
+
+    fn named_id(world: &World<TestEntity>, name: &str) -> Option<Id> {
+        world.get_names().get(name).cloned()
+    }
+
+    #[test]
+    fn push_pop_and_replace_own_and_despawn_entities() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut sync_data = SyncData::new();
+        let mut scenes = SceneManager::new();
+        scenes.push(Scene::new(Box::new(spawn_menu)));
+        assert!(scenes.has_pending());
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        assert_eq!(scenes.get_depth(), 1);
+        assert_eq!(scenes.get_top_entities().map(|entities| entities.len()), Some(2));
+        let menu = named_id(&world, "menu").unwrap();
+        assert!(scenes.is_owned_by_top(menu));
+
+        scenes.push(Scene::new(Box::new(spawn_level)));
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        assert_eq!(scenes.get_depth(), 2);
+        let player = named_id(&world, "player").unwrap();
+        assert!(scenes.is_owned_by_top(player));
+        assert!(!scenes.is_owned_by_top(menu));
+
+        scenes.pop();
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        world.tick_mut();
+        assert_eq!(scenes.get_depth(), 1);
+        assert!(!world.is_entity_alive(player));
+        assert!(named_id(&world, "player").is_none());
+        assert!(world.is_entity_alive(menu));
+
+        scenes.replace(Scene::new(Box::new(spawn_level)));
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        world.tick_mut();
+        assert_eq!(scenes.get_depth(), 1);
+        assert!(!world.is_entity_alive(menu));
+        assert_eq!(world.get_entities().len(), 1);
+        assert!(scenes.is_owned_by_top(named_id(&world, "player").unwrap()));
+
+        scenes.clear(&mut manager, &mut world, &mut sync_data).unwrap();
+        world.tick_mut();
+        assert!(scenes.is_empty());
+        assert!(world.get_entities().is_empty());
+    }
+
+    #[test]
+    fn failed_replace_restores_the_old_scene_and_keeps_later_transitions() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut sync_data = SyncData::new();
+        let mut scenes = SceneManager::new();
+        scenes.push(Scene::new(Box::new(spawn_menu)));
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+
+        scenes.replace(Scene::new(Box::new(spawn_broken)));
+        scenes.push(Scene::new(Box::new(spawn_level)));
+        assert!(scenes.tick_mut(&mut manager, &mut world, &mut sync_data).is_err());
+        world.tick_mut();
+        assert_eq!(scenes.get_depth(), 1);
+        assert!(scenes.has_pending());
+        assert!(named_id(&world, "broken").is_none());
+        let menu = named_id(&world, "menu").unwrap();
+        assert!(world.is_entity_alive(menu));
+        assert!(scenes.is_owned_by_top(menu));
+        assert_eq!(world.get_entities().len(), 2);
+
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        assert_eq!(scenes.get_depth(), 2);
+        assert!(!scenes.has_pending());
+    }
+
+    #[test]
+    fn failed_replace_never_exits_the_old_scene() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut sync_data = SyncData::new();
+        let mut scenes = SceneManager::new();
+        scenes.push(Scene::new(Box::new(spawn_menu)).with_on_exit(Box::new(mark_exit)));
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        let menu = named_id(&world, "menu").unwrap();
+
+        scenes.replace(Scene::new(Box::new(spawn_broken)));
+        assert!(scenes.tick_mut(&mut manager, &mut world, &mut sync_data).is_err());
+        world.tick_mut();
+        assert!(named_id(&world, "exited").is_none());
+        assert_eq!(named_id(&world, "menu"), Some(menu));
+        assert_eq!(world.get_entities().len(), 2);
+
+        scenes.replace(Scene::new(Box::new(spawn_level)).with_on_enter(Box::new(fail)));
+        assert!(scenes.tick_mut(&mut manager, &mut world, &mut sync_data).is_err());
+        world.tick_mut();
+        assert!(named_id(&world, "player").is_none());
+        assert_eq!(named_id(&world, "menu"), Some(menu));
+        assert!(scenes.is_owned_by_top(menu));
+    }
+
+    #[test]
+    fn replacing_a_scene_with_itself_reuses_its_names() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut sync_data = SyncData::new();
+        let mut scenes = SceneManager::new();
+        scenes.push(Scene::new(Box::new(spawn_menu)));
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        let old_menu = named_id(&world, "menu").unwrap();
+
+        scenes.replace(Scene::new(Box::new(spawn_menu)));
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        world.tick_mut();
+        let menu = named_id(&world, "menu").unwrap();
+        assert!(menu != old_menu);
+        assert!(!world.is_entity_alive(old_menu));
+        assert!(scenes.is_owned_by_top(menu));
+        assert_eq!(world.get_entities().len(), 2);
+        assert_eq!(scenes.get_depth(), 1);
+    }
+
+    #[test]
+    fn failed_enter_despawns_what_it_spawned() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut sync_data = SyncData::new();
+        let mut scenes = SceneManager::new();
+        scenes.push(Scene::new(Box::new(spawn_level)).with_on_enter(Box::new(fail)));
+        assert!(scenes.tick_mut(&mut manager, &mut world, &mut sync_data).is_err());
+        world.tick_mut();
+        assert!(scenes.is_empty());
+        assert!(world.get_entities().is_empty());
+        assert!(named_id(&world, "player").is_none());
+    }
+
+    #[test]
+    fn failed_exit_keeps_the_scene() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let mut sync_data = SyncData::new();
+        let mut scenes = SceneManager::new();
+        scenes.push(Scene::new(Box::new(spawn_menu)).with_on_exit(Box::new(fail)));
+        scenes.tick_mut(&mut manager, &mut world, &mut sync_data).unwrap();
+        scenes.pop();
+        assert!(scenes.tick_mut(&mut manager, &mut world, &mut sync_data).is_err());
+        world.tick_mut();
+        assert_eq!(scenes.get_depth(), 1);
+        let menu = named_id(&world, "menu").unwrap();
+        assert!(world.is_entity_alive(menu));
+        assert!(scenes.is_owned_by_top(menu));
+    }
 }
@@ -1,12 +1,21 @@
-use math::{Vec3, Mat4};
+use math::{Vec3, Mat4, Quat};
+use logic::{Id};
 use components::{Renderable};
 use err::DorpErr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Transform {
     position: Vec3,
-    rotation: Vec3,
+    rotation: Quat,
     scalation: Vec3,
+    previous_position: Vec3,
+    previous_rotation: Quat,
+    previous_scalation: Vec3,
+    parent: Option<Id>,
+    parent_matrix: Mat4,
+    render_parent_matrix: Mat4,
+    world_matrix: Mat4,
+    dirty_world: bool,
     dirty_render: bool,
 }
 
@@ -14,35 +23,146 @@ impl Transform {
     pub fn new() -> Transform {
         Transform {
             position: Vec3::zero(),
-            rotation: Vec3::zero(),
+            rotation: Quat::identity(),
             scalation: Vec3::one(),
+            previous_position: Vec3::zero(),
+            previous_rotation: Quat::identity(),
+            previous_scalation: Vec3::one(),
+            parent: None,
+            parent_matrix: Mat4::identity(),
+            render_parent_matrix: Mat4::identity(),
+            world_matrix: Mat4::identity(),
+            dirty_world: true,
             dirty_render: true,
         }
     }
 
-    pub fn render(&mut self, renderable: &mut Renderable) -> Result<(), DorpErr> {
+    pub fn compose(parent: Mat4, local: Mat4) -> Mat4 {
+        (parent * local).to_transpose()
+    }
+
+    fn build_local(position: Vec3, rotation: Quat, scalation: Vec3) -> Mat4 {
+        Mat4::scalation_from_vec3(scalation) * rotation.to_mat4().to_transpose() * Mat4::translation_from_vec3(position)
+    }
+
+    pub fn tick_mut(&mut self) {
+        if self.is_moving() {
+            self.dirty_render = true;
+        }
+        self.previous_position = self.position;
+        self.previous_rotation = self.rotation;
+        self.previous_scalation = self.scalation;
+    }
+
+    pub fn update_world(&mut self, parent_matrix: Option<Mat4>) -> bool {
+        match parent_matrix {
+            Some(parent_matrix) => if parent_matrix != self.parent_matrix {
+                self.parent_matrix = parent_matrix;
+                self.render_parent_matrix = parent_matrix;
+                self.dirty_world = true;
+            },
+            None => if self.parent.is_some() {
+                self.set_parent(None, Mat4::identity());
+            },
+        }
+        if !self.dirty_world {
+            return false;
+        }
+        let local = self.get_local_matrix();
+        self.world_matrix = match self.parent {
+            Some(_) => Transform::compose(self.parent_matrix, local),
+            None => local,
+        };
+        self.dirty_world = false;
+        self.dirty_render = true;
+        true
+    }
+
+    // World::update_render_transforms hands children their parent's interpolated world matrix before entities render
+    pub fn set_render_parent_matrix(&mut self, parent_matrix: Mat4) {
+        if parent_matrix != self.render_parent_matrix {
+            self.render_parent_matrix = parent_matrix;
+            self.dirty_render = true;
+        }
+    }
+
+    pub fn get_interpolated_world_matrix(&self, alpha: f64) -> Mat4 {
+        let alpha = alpha as f32;
+        let position = self.previous_position + (self.position - self.previous_position) * alpha;
+        let rotation = self.previous_rotation.slerp(self.rotation, alpha);
+        let scalation = self.previous_scalation + (self.scalation - self.previous_scalation) * alpha;
+        let local = Transform::build_local(position, rotation, scalation);
+        match self.parent {
+            Some(_) => Transform::compose(self.render_parent_matrix, local),
+            None => local,
+        }
+    }
+
+    pub fn render(&mut self, alpha: f64, renderable: &mut Renderable) -> Result<(), DorpErr> {
         if self.dirty_render {
-            match renderable.set_model(Mat4::scalation_from_vec3(self.scalation) * Mat4::rotation_from_vec3(self.rotation) * Mat4::translation_from_vec3(self.position)) {
+            let model = self.get_interpolated_world_matrix(alpha);
+            match renderable.set_model(model) {
                 Ok(()) => (),
                 Err(err) => return Err(DorpErr::Dorp("Renderable Set Model", Box::new(err))),
             }
-            self.dirty_render = false;
+            self.dirty_render = self.is_moving();
         }
         Ok(())
     }
 
+    fn is_moving(&self) -> bool {
+        self.previous_position != self.position || self.previous_rotation != self.rotation || self.previous_scalation != self.scalation
+    }
+
+    pub fn snap(&mut self) {
+        self.previous_position = self.position;
+        self.previous_rotation = self.rotation;
+        self.previous_scalation = self.scalation;
+        self.dirty_render = true;
+    }
+
     pub fn set_position(&mut self, pos: Vec3) {
         self.position = pos;
+        self.dirty_world = true;
         self.dirty_render = true;
     }
 
-    pub fn set_rotation(&mut self, rot: Vec3) {
-        self.rotation = rot;
+    pub fn set_rotation(&mut self, rot: Quat) {
+        self.rotation = rot.normalize();
+        self.dirty_world = true;
         self.dirty_render = true;
     }
 
+    pub fn set_rotation_euler(&mut self, rot: Vec3) {
+        self.set_rotation(Quat::from_euler(rot));
+    }
+
+    pub fn rotate(&mut self, rot: Quat) {
+        let rotation = rot * self.rotation;
+        self.set_rotation(rotation);
+    }
+
     pub fn set_scalation(&mut self, sca: Vec3) {
         self.scalation = sca;
+        self.dirty_world = true;
+        self.dirty_render = true;
+    }
+
+    pub fn set_parent(&mut self, parent: Option<Id>, parent_matrix: Mat4) {
+        let world_position = self.get_world_position();
+        let local_position = match parent {
+            Some(_) => Vec3::from(parent_matrix.to_transpose().to_inverse() * world_position.to_vec4(1.0)),
+            None => world_position,
+        };
+        self.parent = parent;
+        self.parent_matrix = match parent {
+            Some(_) => parent_matrix,
+            None => Mat4::identity(),
+        };
+        self.render_parent_matrix = self.parent_matrix;
+        self.position = local_position;
+        self.previous_position = local_position;
+        self.dirty_world = true;
         self.dirty_render = true;
     }
 
@@ -50,11 +170,50 @@ impl Transform {
         self.position
     }
 
-    pub fn get_rotation(&self) -> Vec3 {
+    pub fn get_rotation(&self) -> Quat {
         self.rotation
     }
 
     pub fn get_scalation(&self) -> Vec3 {
         self.scalation
     }
+
+    pub fn get_previous_position(&self) -> Vec3 {
+        self.previous_position
+    }
+
+    pub fn get_previous_rotation(&self) -> Quat {
+        self.previous_rotation
+    }
+
+    pub fn get_previous_scalation(&self) -> Vec3 {
+        self.previous_scalation
+    }
+
+    pub fn get_parent(&self) -> Option<Id> {
+        self.parent
+    }
+
+    pub fn get_local_matrix(&self) -> Mat4 {
+        Transform::build_local(self.position, self.rotation, self.scalation)
+    }
+
+    pub fn get_world_matrix(&self) -> Mat4 {
+        if self.dirty_world {
+            let local = self.get_local_matrix();
+            return match self.parent {
+                Some(_) => Transform::compose(self.parent_matrix, local),
+                None => local,
+            };
+        }
+        self.world_matrix
+    }
+
+    pub fn get_world_position(&self) -> Vec3 {
+        Vec3::from(self.get_world_matrix().to_transpose() * Vec3::zero().to_vec4(1.0))
+    }
+
+    pub fn is_world_dirty(&self) -> bool {
+        self.dirty_world
+    }
 }
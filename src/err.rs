@@ -1,5 +1,9 @@
+use std::io;
 use glium;
 use image;
+use yaml_rust;
+
+use logic::{Id};
 
 #[derive(Debug)]
 pub enum DorpErr {
@@ -9,6 +13,7 @@ pub enum DorpErr {
     GliumProgramCreationString(String, glium::program::ProgramCreationError),
     Base(&'static str),
     Dorp(&'static str, Box<DorpErr>),
+    Entities(&'static str, Vec<(Id, DorpErr)>),
     GliumDraw(&'static str, glium::DrawError),
     GliumProgramCreation(&'static str, glium::program::ProgramCreationError),
     GliumVertexBufferCreation(&'static str, glium::vertex::BufferCreationError),
@@ -17,4 +22,7 @@ pub enum DorpErr {
     Image(&'static str, image::ImageError),
     GliumSwapBuffers(&'static str, glium::SwapBuffersError),
     GliumCreation(&'static str, glium::GliumCreationError<glium::glutin::CreationError>),
+    Io(&'static str, io::Error),
+    YamlScan(&'static str, yaml_rust::ScanError),
+    YamlEmit(&'static str, yaml_rust::EmitError),
 }
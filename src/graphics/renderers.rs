@@ -2,6 +2,7 @@ use graphics::texture2d::{RendererTex2};
 use graphics::solid_color::{RendererSolidColor};
 use graphics::vertex_color::{RendererVertexColor};
 use graphics::{Window};
+use math::{Mat4};
 use err::DorpErr;
 
 #[derive(Debug)]
@@ -9,6 +10,7 @@ pub struct Renderers {
     renderer_solid_color: RendererSolidColor,
     renderer_vertex_color: RendererVertexColor,
     renderer_texture2d: RendererTex2,
+    camera: Option<(Mat4, Mat4)>,
 }
 
 impl Renderers {
@@ -27,6 +29,7 @@ impl Renderers {
                     Ok(tex2) => tex2,
                     Err(err) => return Err(DorpErr::Dorp("RendererTex2 New", Box::new(err))),
                 },
+                camera: None,
             }
         )
     }
@@ -42,6 +45,18 @@ impl Renderers {
     pub fn get_mut_texture2d(&mut self) -> &mut RendererTex2 {
         &mut self.renderer_texture2d
     }
+
+    pub fn bind_camera(&mut self, perspective: Mat4, view: Mat4) {
+        self.camera = Some((perspective, view));
+    }
+
+    pub fn unbind_camera(&mut self) {
+        self.camera = None;
+    }
+
+    pub fn get_camera(&self) -> Option<(Mat4, Mat4)> {
+        self.camera
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -4,16 +4,17 @@ use glium::{Surface, VertexBuffer, IndexBuffer, DrawParameters, Program};
 use glium;
 
 use components::{Renderable};
-use logic::{Id};
+use logic::{VertexId, IndexId, DrawMethodId};
 use graphics::{SyncData, Window};
 use graphics::solid_color::{Vertex, init_vertex, Index, DrawMethod, method_to_parameters};
+use math::{Mat4};
 use err::DorpErr;
 
 #[derive(Debug)]
 pub struct RendererSolidColor {
-    vertex_buffers: HashMap<Id, VertexBuffer<Vertex>>,
-    index_buffers: HashMap<Id, IndexBuffer<Index>>,
-    draw_parameters: HashMap<Id, DrawParameters<'static>>,
+    vertex_buffers: HashMap<VertexId, VertexBuffer<Vertex>>,
+    index_buffers: HashMap<IndexId, IndexBuffer<Index>>,
+    draw_parameters: HashMap<DrawMethodId, DrawParameters<'static>>,
     program: Program,
 }
 
@@ -58,7 +59,7 @@ impl RendererSolidColor {
         )
     }
 
-    pub fn set_vertices(&mut self, id: Id, window: &mut Window, vertices: Vec<Vertex>) -> Result<(), DorpErr> {
+    pub fn set_vertices(&mut self, id: VertexId, window: &mut Window, vertices: Vec<Vertex>) -> Result<(), DorpErr> {
         self.vertex_buffers.insert(id, match VertexBuffer::new(window.get_facade(), &vertices) {
             Ok(buffer) => buffer,
             Err(err) => return Err(DorpErr::GliumVertexBufferCreation("VertexBuffer New", err)),
@@ -66,7 +67,7 @@ impl RendererSolidColor {
         Ok(())
     }
 
-    pub fn set_indices(&mut self, id: Id, window: &mut Window, indices: Vec<Index>) -> Result<(), DorpErr> {
+    pub fn set_indices(&mut self, id: IndexId, window: &mut Window, indices: Vec<Index>) -> Result<(), DorpErr> {
         self.index_buffers.insert(id, match IndexBuffer::new(window.get_facade(), glium::index::PrimitiveType::TrianglesList, &indices) {
             Ok(buffer) => buffer,
             Err(err) => return Err(DorpErr::GliumIndexBufferCreation("IndexBuffer New", err)),
@@ -74,11 +75,11 @@ impl RendererSolidColor {
         Ok(())
     }
 
-    pub fn set_draw_method(&mut self, id: Id, draw_method: DrawMethod) {
+    pub fn set_draw_method(&mut self, id: DrawMethodId, draw_method: DrawMethod) {
         self.draw_parameters.insert(id, method_to_parameters(draw_method));
     }
 
-    pub fn render(&mut self, frame: &mut GliumFrame, renderable: &Renderable, sync_data: &SyncData) -> Result<(), DorpErr> {
+    pub fn render(&mut self, frame: &mut GliumFrame, renderable: &Renderable, sync_data: &SyncData, camera: Option<(Mat4, Mat4)>) -> Result<(), DorpErr> {
         let renderable_solid = match renderable.get_solid_color() {
             Some(renderable) => renderable,
             None => return Err(DorpErr::Base("Renderable Get Solid Color was none")),
@@ -98,13 +99,19 @@ impl RendererSolidColor {
                     Some(color) => *color,
                     None => return Err(DorpErr::Base("Sync Data Get Vec4 was none")),
                 },
-                perspective: match sync_data.get_matrix(renderable_solid.get_perspective_id()) {
-                    Some(perspective) => *perspective,
-                    None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                perspective: match camera {
+                    Some((perspective, _)) => perspective,
+                    None => match sync_data.get_matrix(renderable_solid.get_perspective_id()) {
+                        Some(perspective) => *perspective,
+                        None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                    },
                 },
-                view: match sync_data.get_matrix(renderable_solid.get_view_id()) {
-                    Some(view) => *view,
-                    None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                view: match camera {
+                    Some((_, view)) => view,
+                    None => match sync_data.get_matrix(renderable_solid.get_view_id()) {
+                        Some(view) => *view,
+                        None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                    },
                 },
                 model: match sync_data.get_matrix(renderable_solid.get_model_id()) {
                     Some(model) => *model,
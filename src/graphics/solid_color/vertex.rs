@@ -9,6 +9,10 @@ impl Vertex {
             position: position,
         }
     }
+
+    pub fn get_position(&self) -> [f32; 3] {
+        self.position
+    }
 }
 
 pub fn init_vertex() {
@@ -1,13 +1,13 @@
 use std::collections::{HashMap};
 
-use logic::{Id};
+use logic::{MatrixId, ColorId};
 use math::{Mat4, Vec4};
 
 #[derive(Debug)]
 pub struct SyncData {
-    mat4s: HashMap<Id, Mat4>,
-    mat4s_inverse: HashMap<Id, Mat4>,
-    vec4s: HashMap<Id, Vec4>,
+    mat4s: HashMap<MatrixId, Mat4>,
+    mat4s_inverse: HashMap<MatrixId, Mat4>,
+    vec4s: HashMap<ColorId, Vec4>,
 }
 
 impl SyncData {
@@ -19,24 +19,24 @@ impl SyncData {
         }
     }
 
-    pub fn set_vec4(&mut self, id: Id, vec4: Vec4) {
+    pub fn set_vec4(&mut self, id: ColorId, vec4: Vec4) {
         self.vec4s.insert(id, vec4);
     }
 
-    pub fn set_matrix(&mut self, id: Id, mat4: Mat4, inverse: Mat4) {
+    pub fn set_matrix(&mut self, id: MatrixId, mat4: Mat4, inverse: Mat4) {
         self.mat4s.insert(id, mat4);
         self.mat4s_inverse.insert(id, inverse);
     }
 
-    pub fn get_vec4(&self, id: Id) -> Option<&Vec4> {
+    pub fn get_vec4(&self, id: ColorId) -> Option<&Vec4> {
         self.vec4s.get(&id)
     }
 
-    pub fn get_matrix(&self, id: Id) -> Option<&Mat4> {
+    pub fn get_matrix(&self, id: MatrixId) -> Option<&Mat4> {
         self.mat4s.get(&id)
     }
 
-    pub fn get_inverse(&self, id: Id) -> Option<&Mat4> {
+    pub fn get_inverse(&self, id: MatrixId) -> Option<&Mat4> {
         self.mat4s_inverse.get(&id)
     }
 }
@@ -6,18 +6,19 @@ use glium::{Surface, VertexBuffer, DrawParameters, IndexBuffer, Program};
 use glium;
 use image::{load_from_memory};
 
-use logic::{Id};
+use logic::{VertexId, IndexId, TextureId, DrawMethodId};
 use components::{Renderable};
 use graphics::{Window, SyncData};
 use graphics::texture2d::{Vertex, Index, DrawMethod, method_to_parameters, init_vertex};
+use math::{Mat4};
 use err::DorpErr;
 
 #[derive(Debug)]
 pub struct RendererTex2 {
-    vertex_buffers: HashMap<Id, VertexBuffer<Vertex>>,
-    index_buffers: HashMap<Id, IndexBuffer<Index>>,
-    texture_buffers: HashMap<Id, Texture2d>,
-    draw_parameters: HashMap<Id, DrawParameters<'static>>,
+    vertex_buffers: HashMap<VertexId, VertexBuffer<Vertex>>,
+    index_buffers: HashMap<IndexId, IndexBuffer<Index>>,
+    texture_buffers: HashMap<TextureId, Texture2d>,
+    draw_parameters: HashMap<DrawMethodId, DrawParameters<'static>>,
     program: Program,
 }
 
@@ -68,7 +69,7 @@ impl RendererTex2 {
         )
     }
 
-    pub fn set_vertices(&mut self, id: Id, window: &mut Window, vertices: Vec<Vertex>) -> Result<(), DorpErr> {
+    pub fn set_vertices(&mut self, id: VertexId, window: &mut Window, vertices: Vec<Vertex>) -> Result<(), DorpErr> {
         self.vertex_buffers.insert(id, match VertexBuffer::new(window.get_facade(), &vertices) {
             Ok(buffer) => buffer,
             Err(err) => return Err(DorpErr::GliumVertexBufferCreation("VertexBuffer New", err)),
@@ -76,7 +77,7 @@ impl RendererTex2 {
         Ok(())
     }
 
-    pub fn set_indices(&mut self, id: Id, window: &mut Window, indices: Vec<Index>) -> Result<(), DorpErr> {
+    pub fn set_indices(&mut self, id: IndexId, window: &mut Window, indices: Vec<Index>) -> Result<(), DorpErr> {
         self.index_buffers.insert(id, match IndexBuffer::new(window.get_facade(), glium::index::PrimitiveType::TrianglesList, &indices) {
             Ok(buffer) => buffer,
             Err(err) => return Err(DorpErr::GliumIndexBufferCreation("IndexBuffer New", err)),
@@ -84,7 +85,7 @@ impl RendererTex2 {
         Ok(())
     }
 
-    pub fn set_texture(&mut self, id: Id, window: &mut Window, data: &[u8]) -> Result<(), DorpErr> {
+    pub fn set_texture(&mut self, id: TextureId, window: &mut Window, data: &[u8]) -> Result<(), DorpErr> {
         let texture = match load_from_memory(data) {
             Ok(texture) => texture,
             Err(err) => return Err(DorpErr::Image("Load From Memory data", err)),
@@ -96,11 +97,11 @@ impl RendererTex2 {
         Ok(())
     }
 
-    pub fn set_draw_method(&mut self, id: Id, draw_method: DrawMethod) {
+    pub fn set_draw_method(&mut self, id: DrawMethodId, draw_method: DrawMethod) {
         self.draw_parameters.insert(id, method_to_parameters(draw_method));
     }
 
-    pub fn render(&mut self, frame: &mut GliumFrame, renderable: &Renderable, sync_data: &SyncData) -> Result<(), DorpErr> {
+    pub fn render(&mut self, frame: &mut GliumFrame, renderable: &Renderable, sync_data: &SyncData, camera: Option<(Mat4, Mat4)>) -> Result<(), DorpErr> {
         let renderable_tex2 = match renderable.get_texture2d() {
             Some(renderable) => renderable,
             None => return Err(DorpErr::Base("Renderable Get Tex2 was none")),
@@ -120,13 +121,19 @@ impl RendererTex2 {
                     Some(texture) => texture,
                     None => return Err(DorpErr::Base("Self Texture Buffers Get was none")),
                 },
-                perspective: match sync_data.get_matrix(renderable_tex2.get_perspective_id()) {
-                    Some(perspective) => *perspective,
-                    None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                perspective: match camera {
+                    Some((perspective, _)) => perspective,
+                    None => match sync_data.get_matrix(renderable_tex2.get_perspective_id()) {
+                        Some(perspective) => *perspective,
+                        None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                    },
                 },
-                view: match sync_data.get_matrix(renderable_tex2.get_view_id()) {
-                    Some(view) => *view,
-                    None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                view: match camera {
+                    Some((_, view)) => view,
+                    None => match sync_data.get_matrix(renderable_tex2.get_view_id()) {
+                        Some(view) => *view,
+                        None => return Err(DorpErr::Base("Matrix Data Get Matrix was none")),
+                    },
                 },
                 model: match sync_data.get_matrix(renderable_tex2.get_model_id()) {
                     Some(model) => *model,
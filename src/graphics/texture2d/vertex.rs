@@ -11,6 +11,10 @@ impl Vertex {
             tex_coord: tex_coord,
         }
     }
+
+    pub fn get_position(&self) -> [f32; 3] {
+        self.position
+    }
 }
 
 pub fn init_vertex() {
@@ -11,6 +11,10 @@ impl Vertex {
             color: color,
         }
     }
+
+    pub fn get_position(&self) -> [f32; 3] {
+        self.position
+    }
 }
 
 
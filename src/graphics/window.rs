@@ -4,7 +4,7 @@ use glium::glutin::{get_primary_monitor};
 use glium::{Surface, DisplayBuild};
 use glium::Frame as GliumFrame;
 
-use logic::{Entity};
+use logic::{Entity, GameConfig};
 use err::DorpErr;
 use graphics::{Renderers, RendererType, SyncData};
 
@@ -28,16 +28,17 @@ impl Frame {
     pub fn draw_entity<T: Entity<T>>(&mut self, entity: &T, sync_data: &SyncData) -> Result<(), DorpErr> {
         match entity.get_renderable() {
             Some(renderable) => {
+                let camera = self.renderers.get_camera();
                 match renderable.get_renderer_type() {
-                    RendererType::SolidColor => match self.renderers.get_mut_solid_color().render(&mut self.frame, renderable, sync_data) {
+                    RendererType::SolidColor => match self.renderers.get_mut_solid_color().render(&mut self.frame, renderable, sync_data, camera) {
                         Ok(()) => Ok(()),
                         Err(err) => Err(DorpErr::Dorp("Self RendererSolidColor Render", Box::new(err))),
                     },
-                    RendererType::VertexColor => match self.renderers.get_mut_vertex_color().render(&mut self.frame, renderable, sync_data) {
+                    RendererType::VertexColor => match self.renderers.get_mut_vertex_color().render(&mut self.frame, renderable, sync_data, camera) {
                         Ok(()) => Ok(()),
                         Err(err) => Err(DorpErr::Dorp("Self RendererVertexColor Render", Box::new(err))),
                     },
-                    RendererType::Texture2d => match self.renderers.get_mut_texture2d().render(&mut self.frame, renderable, sync_data) {
+                    RendererType::Texture2d => match self.renderers.get_mut_texture2d().render(&mut self.frame, renderable, sync_data, camera) {
                         Ok(()) => Ok(()),
                         Err(err) => Err(DorpErr::Dorp("Self Renderer Texture2d Render", Box::new(err))),
                     },
@@ -79,6 +80,7 @@ pub struct WindowBuilder {
     windowed: Windowed,
     dimensions: (u32, u32),
     title: String,
+    vsync: bool,
 }
 
 impl WindowBuilder {
@@ -87,6 +89,7 @@ impl WindowBuilder {
             windowed: Windowed::Windowed,
             dimensions: (640, 480),
             title: "Untitled".to_string(),
+            vsync: true,
         }
     }
 
@@ -110,6 +113,11 @@ impl WindowBuilder {
         self
     }
 
+    pub fn with_config(mut self, config: &GameConfig) -> WindowBuilder {
+        self.vsync = config.get_vsync();
+        self
+    }
+
     pub fn build(self) -> Result<(Window, (u32, u32)), DorpErr> {
         let resolution: (u32, u32) = get_primary_monitor().get_dimensions();
         Ok(
@@ -117,13 +125,17 @@ impl WindowBuilder {
                 Window {
                     facade: match self.windowed {
                         Windowed::Windowed => {
-                            let facade = match GlutinWindowBuilder::new()
+                            let builder = GlutinWindowBuilder::new()
                                 .with_title(self.title)
                                 .with_dimensions(self.dimensions.0, self.dimensions.1)
                                 .with_decorations(true)
-                                .with_depth_buffer(24)
-                                .with_vsync()
-                                .build_glium() {
+                                .with_depth_buffer(24);
+                            let builder = if self.vsync {
+                                builder.with_vsync()
+                            } else {
+                                builder
+                            };
+                            let facade = match builder.build_glium() {
                                     Ok(facade) => facade,
                                     Err(err) => return Err(DorpErr::GliumCreation("GlutinWindowBuilder Build Glium", err)),
                                 };
@@ -134,13 +146,17 @@ impl WindowBuilder {
                             facade
                         },
                         Windowed::Borderless => {
-                            let facade = match GlutinWindowBuilder::new()
+                            let builder = GlutinWindowBuilder::new()
                                 .with_title(self.title)
                                 .with_dimensions(resolution.0, resolution.1)
                                 .with_decorations(false)
-                                .with_depth_buffer(24)
-                                .with_vsync()
-                                .build_glium() {
+                                .with_depth_buffer(24);
+                            let builder = if self.vsync {
+                                builder.with_vsync()
+                            } else {
+                                builder
+                            };
+                            let facade = match builder.build_glium() {
                                     Ok(facade) => facade,
                                     Err(err) => return Err(DorpErr::GliumCreation("GlutinWindowBuilder Build Glium", err)),
                                 };
@@ -1,24 +1,115 @@
 use input::{ButtonState};
+use logic::{TickCount};
 
 #[derive(Debug, Copy, Clone)]
 pub struct Button {
-    tick_number: u64,
+    pressed_tick: Option<TickCount>,
+    released_tick: Option<TickCount>,
     state: ButtonState,
 }
 
 impl Button {
     pub fn new(tick_number: u64, state: ButtonState) -> Button {
+        let mut button = Button::never_pressed();
+        button.update(tick_number, state);
+        button
+    }
+
+    pub fn never_pressed() -> Button {
         Button {
-            tick_number: tick_number,
-            state: state,
+            pressed_tick: None,
+            released_tick: None,
+            state: ButtonState::Released,
         }
     }
 
+    // Press and release ticks are kept apart so a tap inside one tick still shows up as both edges
+    pub fn update(&mut self, tick_number: u64, state: ButtonState) {
+        match state {
+            ButtonState::Pressed => self.pressed_tick = Some(tick_number),
+            ButtonState::Released => self.released_tick = Some(tick_number),
+        }
+        self.state = state;
+    }
+
     pub fn get_tick_number(&self) -> u64 {
-        self.tick_number
+        match self.state {
+            ButtonState::Pressed => self.pressed_tick,
+            ButtonState::Released => self.released_tick,
+        }.unwrap_or(0)
+    }
+
+    pub fn was_ever_pressed(&self) -> bool {
+        self.pressed_tick.is_some()
     }
 
     pub fn get_state(&self) -> ButtonState {
         self.state
     }
+
+    pub fn is_pressed(&self) -> bool {
+        self.state == ButtonState::Pressed
+    }
+
+    pub fn is_released(&self) -> bool {
+        self.state == ButtonState::Released
+    }
+
+    pub fn was_just_pressed(&self, tick: TickCount) -> bool {
+        self.pressed_tick == Some(tick)
+    }
+
+    pub fn was_just_released(&self, tick: TickCount) -> bool {
+        self.released_tick == Some(tick)
+    }
+
+    pub fn get_held_ticks(&self, tick: TickCount) -> TickCount {
+        match (self.state, self.pressed_tick) {
+            (ButtonState::Pressed, Some(pressed_tick)) => tick.saturating_sub(pressed_tick),
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{Button, ButtonState};
+
+    #[test]
+    fn never_pressed_reports_no_edges() {
+        let button = Button::never_pressed();
+        assert!(!button.was_ever_pressed());
+        assert!(button.is_released());
+        assert!(!button.was_just_pressed(0));
+        assert!(!button.was_just_released(0));
+        assert_eq!(button.get_held_ticks(5), 0);
+    }
+
+    #[test]
+    fn edges_only_fire_on_their_tick() {
+        let mut button = Button::never_pressed();
+        button.update(2, ButtonState::Pressed);
+        assert!(button.was_just_pressed(2));
+        assert!(!button.was_just_pressed(3));
+        assert!(!button.was_just_released(2));
+        assert_eq!(button.get_held_ticks(6), 4);
+        button.update(6, ButtonState::Released);
+        assert!(button.was_just_released(6));
+        assert!(!button.was_just_released(7));
+        assert!(!button.was_just_pressed(6));
+        assert_eq!(button.get_held_ticks(6), 0);
+        assert_eq!(button.get_tick_number(), 6);
+    }
+
+    #[test]
+    fn tap_within_one_tick_reports_both_edges() {
+        let mut button = Button::never_pressed();
+        button.update(4, ButtonState::Pressed);
+        button.update(4, ButtonState::Released);
+        assert!(button.is_released());
+        assert!(button.was_ever_pressed());
+        assert!(button.was_just_pressed(4));
+        assert!(button.was_just_released(4));
+        assert_eq!(button.get_held_ticks(4), 0);
+    }
 }
@@ -1,4 +1,4 @@
-use math::{Vec2};
+use math::{Vec2, Mat4};
 
 #[derive(Debug)]
 pub struct Display {
@@ -26,4 +26,8 @@ impl Display {
     pub fn get_aspect_ratio(&self) -> f32 {
         self.aspect_ratio
     }
+
+    pub fn get_screen_projection(&self) -> Mat4 {
+        Mat4::screen_space(self.resolution, -1.0, 1.0)
+    }
 }
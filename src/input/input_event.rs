@@ -0,0 +1,91 @@
+use std::path::{PathBuf};
+
+use input::{KeyCode, MouseButton, ButtonState, TouchPhase};
+use input::names::{key_code_from_str, key_code_to_string, mouse_button_from_str, mouse_button_to_string, button_state_from_str, button_state_to_string, touch_phase_from_str, touch_phase_to_string};
+use err::DorpErr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputEvent {
+    Key(KeyCode, ButtonState),
+    MouseButton(MouseButton, ButtonState),
+    MousePosition(i32, i32),
+    Resolution(u32, u32),
+    Focused(bool),
+    Character(char),
+    ScrollLines(f32, f32),
+    ScrollPixels(f32, f32),
+    DroppedFile(PathBuf),
+    Touch(u64, TouchPhase, f32, f32),
+}
+
+impl InputEvent {
+    pub fn to_line(&self) -> String {
+        match *self {
+            InputEvent::Key(key_code, state) => format!("key {} {}", key_code_to_string(key_code), button_state_to_string(state)),
+            InputEvent::MouseButton(mouse_button, state) => format!("mouse_button {} {}", mouse_button_to_string(mouse_button), button_state_to_string(state)),
+            InputEvent::MousePosition(x, y) => format!("mouse_position {} {}", x, y),
+            InputEvent::Resolution(width, height) => format!("resolution {} {}", width, height),
+            InputEvent::Focused(focused) => format!("focused {}", focused),
+            InputEvent::Character(character) => format!("character {}", character as u32),
+            InputEvent::ScrollLines(x, y) => format!("scroll_lines {} {}", x, y),
+            InputEvent::ScrollPixels(x, y) => format!("scroll_pixels {} {}", x, y),
+            InputEvent::DroppedFile(ref path) => format!("dropped_file {}", path.to_string_lossy()),
+            InputEvent::Touch(id, phase, x, y) => format!("touch {} {} {} {}", id, touch_phase_to_string(phase), x, y),
+        }
+    }
+
+    pub fn from_line(line: &str) -> Result<InputEvent, DorpErr> {
+        if line.starts_with("dropped_file ") {
+            return Ok(InputEvent::DroppedFile(PathBuf::from(&line["dropped_file ".len()..])));
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let word_count = match words.first() {
+            Some(&"focused") | Some(&"character") => 2,
+            Some(&"touch") => 5,
+            Some(_) => 3,
+            None => return Err(DorpErr::BaseString("Empty input event line".to_string())),
+        };
+        if words.len() != word_count {
+            return Err(DorpErr::BaseString(format!("Input Event line needs {} words: {}", word_count, line)));
+        }
+        match words[0] {
+            "key" => match (key_code_from_str(words[1]), button_state_from_str(words[2])) {
+                (Some(key_code), Some(state)) => Ok(InputEvent::Key(key_code, state)),
+                _ => Err(DorpErr::BaseString("Invalid key event: ".to_string() + line)),
+            },
+            "mouse_button" => match (mouse_button_from_str(words[1]), button_state_from_str(words[2])) {
+                (Some(mouse_button), Some(state)) => Ok(InputEvent::MouseButton(mouse_button, state)),
+                _ => Err(DorpErr::BaseString("Invalid mouse button event: ".to_string() + line)),
+            },
+            "mouse_position" => match (words[1].parse::<i32>(), words[2].parse::<i32>()) {
+                (Ok(x), Ok(y)) => Ok(InputEvent::MousePosition(x, y)),
+                _ => Err(DorpErr::BaseString("Invalid mouse position event: ".to_string() + line)),
+            },
+            "resolution" => match (words[1].parse::<u32>(), words[2].parse::<u32>()) {
+                (Ok(width), Ok(height)) => Ok(InputEvent::Resolution(width, height)),
+                _ => Err(DorpErr::BaseString("Invalid resolution event: ".to_string() + line)),
+            },
+            "focused" => match words[1].parse::<bool>() {
+                Ok(focused) => Ok(InputEvent::Focused(focused)),
+                Err(_) => Err(DorpErr::BaseString("Invalid focused event: ".to_string() + line)),
+            },
+            "character" => match words[1].parse::<u32>().ok().and_then(::std::char::from_u32) {
+                Some(character) => Ok(InputEvent::Character(character)),
+                None => Err(DorpErr::BaseString("Invalid character event: ".to_string() + line)),
+            },
+            "scroll_lines" => match (words[1].parse::<f32>(), words[2].parse::<f32>()) {
+                (Ok(x), Ok(y)) => Ok(InputEvent::ScrollLines(x, y)),
+                _ => Err(DorpErr::BaseString("Invalid scroll lines event: ".to_string() + line)),
+            },
+            "scroll_pixels" => match (words[1].parse::<f32>(), words[2].parse::<f32>()) {
+                (Ok(x), Ok(y)) => Ok(InputEvent::ScrollPixels(x, y)),
+                _ => Err(DorpErr::BaseString("Invalid scroll pixels event: ".to_string() + line)),
+            },
+            "touch" => match (words[1].parse::<u64>(), touch_phase_from_str(words[2]), words[3].parse::<f32>(), words[4].parse::<f32>()) {
+                (Ok(id), Some(phase), Ok(x), Ok(y)) => Ok(InputEvent::Touch(id, phase, x, y)),
+                _ => Err(DorpErr::BaseString("Invalid touch event: ".to_string() + line)),
+            },
+            _ => Err(DorpErr::BaseString("Unknown input event: ".to_string() + line)),
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use std::collections::{HashMap};
+use std::fs::{File};
+use std::io::{Read};
+use std::path::{Path};
+use yaml_rust::{YamlLoader, Yaml};
+
+use input::{Keyboard, Mouse, Button, KeyCode, MouseButton};
+use input::names::{key_code_from_str, key_code_to_string, mouse_button_from_str, mouse_button_to_string};
+use logic::{TickCount};
+use err::DorpErr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Binding {
+    Key(KeyCode),
+    MouseButton(MouseButton),
+}
+
+impl Binding {
+    pub fn from_str(name: &str) -> Option<Binding> {
+        if name.starts_with("Mouse") {
+            match mouse_button_from_str(&name[5..]) {
+                Some(mouse_button) => return Some(Binding::MouseButton(mouse_button)),
+                None => (),
+            }
+        }
+        match key_code_from_str(name) {
+            Some(key_code) => Some(Binding::Key(key_code)),
+            None => None,
+        }
+    }
+
+    pub fn to_string(&self) -> String {
+        match *self {
+            Binding::Key(key_code) => key_code_to_string(key_code),
+            Binding::MouseButton(mouse_button) => "Mouse".to_string() + &mouse_button_to_string(mouse_button),
+        }
+    }
+
+    fn get_button(&self, keyboard: &Keyboard, mouse: &Mouse) -> Button {
+        match *self {
+            Binding::Key(key_code) => keyboard.get_key(key_code),
+            Binding::MouseButton(mouse_button) => mouse.get_button(mouse_button),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Axis {
+    positive: Vec<Binding>,
+    negative: Vec<Binding>,
+}
+
+impl Axis {
+    pub fn new(positive: Vec<Binding>, negative: Vec<Binding>) -> Axis {
+        Axis {
+            positive: positive,
+            negative: negative,
+        }
+    }
+
+    pub fn get_positive(&self) -> &Vec<Binding> {
+        &self.positive
+    }
+
+    pub fn get_negative(&self) -> &Vec<Binding> {
+        &self.negative
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InputMap {
+    actions: HashMap<String, Vec<Binding>>,
+    axes: HashMap<String, Axis>,
+}
+
+impl InputMap {
+    pub fn new() -> InputMap {
+        InputMap {
+            actions: HashMap::new(),
+            axes: HashMap::new(),
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<InputMap, DorpErr> {
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => return Err(DorpErr::Io("File Open", err)),
+        };
+        let mut text = String::new();
+        match file.read_to_string(&mut text) {
+            Ok(_) => (),
+            Err(err) => return Err(DorpErr::Io("File Read To String", err)),
+        }
+        match InputMap::from_yaml_str(&text) {
+            Ok(input_map) => Ok(input_map),
+            Err(err) => Err(DorpErr::Dorp("Input Map From Yaml Str", Box::new(err))),
+        }
+    }
+
+    pub fn from_yaml_str(text: &str) -> Result<InputMap, DorpErr> {
+        let docs = match YamlLoader::load_from_str(text) {
+            Ok(docs) => docs,
+            Err(err) => return Err(DorpErr::YamlScan("Yaml Loader Load From Str", err)),
+        };
+        let mut input_map = InputMap::new();
+        let doc = match docs.get(0) {
+            Some(doc) => doc,
+            None => return Ok(input_map),
+        };
+        if let Some(actions) = doc["actions"].as_hash() {
+            for (name, bindings) in actions.iter() {
+                let name = match name.as_str() {
+                    Some(name) => name,
+                    None => return Err(DorpErr::Base("Action name was not a string")),
+                };
+                match parse_bindings(bindings) {
+                    Ok(bindings) => input_map.rebind_action(name, bindings),
+                    Err(err) => return Err(DorpErr::DorpString("Parse Bindings for action: ".to_string() + name, Box::new(err))),
+                }
+            }
+        }
+        if let Some(axes) = doc["axes"].as_hash() {
+            for (name, axis) in axes.iter() {
+                let name = match name.as_str() {
+                    Some(name) => name,
+                    None => return Err(DorpErr::Base("Axis name was not a string")),
+                };
+                let positive = match parse_bindings(&axis["positive"]) {
+                    Ok(bindings) => bindings,
+                    Err(err) => return Err(DorpErr::DorpString("Parse Bindings for positive axis: ".to_string() + name, Box::new(err))),
+                };
+                let negative = match parse_bindings(&axis["negative"]) {
+                    Ok(bindings) => bindings,
+                    Err(err) => return Err(DorpErr::DorpString("Parse Bindings for negative axis: ".to_string() + name, Box::new(err))),
+                };
+                input_map.rebind_axis(name, Axis::new(positive, negative));
+            }
+        }
+        Ok(input_map)
+    }
+
+    pub fn bind_action(&mut self, name: &str, binding: Binding) {
+        let bindings = self.actions.entry(name.to_string()).or_insert(vec!());
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    pub fn unbind_action(&mut self, name: &str, binding: Binding) {
+        if let Some(bindings) = self.actions.get_mut(name) {
+            bindings.retain(|other| *other != binding);
+        }
+    }
+
+    pub fn rebind_action(&mut self, name: &str, bindings: Vec<Binding>) {
+        self.actions.insert(name.to_string(), bindings);
+    }
+
+    pub fn rebind_axis(&mut self, name: &str, axis: Axis) {
+        self.axes.insert(name.to_string(), axis);
+    }
+
+    pub fn remove_action(&mut self, name: &str) {
+        self.actions.remove(name);
+    }
+
+    pub fn remove_axis(&mut self, name: &str) {
+        self.axes.remove(name);
+    }
+
+    pub fn get_action_bindings(&self, name: &str) -> Option<&Vec<Binding>> {
+        self.actions.get(name)
+    }
+
+    pub fn get_axis(&self, name: &str) -> Option<&Axis> {
+        self.axes.get(name)
+    }
+
+    pub fn is_action_pressed(&self, name: &str, keyboard: &Keyboard, mouse: &Mouse) -> bool {
+        match self.actions.get(name) {
+            Some(bindings) => any_pressed(bindings, keyboard, mouse),
+            None => false,
+        }
+    }
+
+    pub fn was_action_just_pressed(&self, name: &str, tick: TickCount, keyboard: &Keyboard, mouse: &Mouse) -> bool {
+        match self.actions.get(name) {
+            Some(bindings) => bindings.iter().any(|binding| binding.get_button(keyboard, mouse).was_just_pressed(tick)) && !any_held_before(bindings, tick, keyboard, mouse),
+            None => false,
+        }
+    }
+
+    pub fn was_action_just_released(&self, name: &str, tick: TickCount, keyboard: &Keyboard, mouse: &Mouse) -> bool {
+        match self.actions.get(name) {
+            Some(bindings) => bindings.iter().any(|binding| binding.get_button(keyboard, mouse).was_just_released(tick)) && !any_pressed(bindings, keyboard, mouse),
+            None => false,
+        }
+    }
+
+    pub fn get_axis_value(&self, name: &str, keyboard: &Keyboard, mouse: &Mouse) -> f32 {
+        match self.axes.get(name) {
+            Some(axis) => {
+                let mut value = 0.0;
+                if any_pressed(&axis.positive, keyboard, mouse) {
+                    value += 1.0;
+                }
+                if any_pressed(&axis.negative, keyboard, mouse) {
+                    value -= 1.0;
+                }
+                value
+            },
+            None => 0.0,
+        }
+    }
+}
+
+fn any_pressed(bindings: &Vec<Binding>, keyboard: &Keyboard, mouse: &Mouse) -> bool {
+    bindings.iter().any(|binding| binding.get_button(keyboard, mouse).is_pressed())
+}
+
+fn any_held_before(bindings: &Vec<Binding>, tick: TickCount, keyboard: &Keyboard, mouse: &Mouse) -> bool {
+    bindings.iter().any(|binding| {
+        let button = binding.get_button(keyboard, mouse);
+        button.is_pressed() && !button.was_just_pressed(tick)
+    })
+}
+
+fn parse_bindings(yaml: &Yaml) -> Result<Vec<Binding>, DorpErr> {
+    let mut bindings = vec!();
+    match *yaml {
+        Yaml::Array(ref names) => {
+            for name in names.iter() {
+                match parse_binding(name) {
+                    Ok(binding) => bindings.push(binding),
+                    Err(err) => return Err(err),
+                }
+            }
+        },
+        Yaml::BadValue | Yaml::Null => (),
+        _ => match parse_binding(yaml) {
+            Ok(binding) => bindings.push(binding),
+            Err(err) => return Err(err),
+        },
+    }
+    Ok(bindings)
+}
+
+fn parse_binding(yaml: &Yaml) -> Result<Binding, DorpErr> {
+    let name = match yaml.as_str() {
+        Some(name) => name,
+        None => return Err(DorpErr::Base("Binding was not a string")),
+    };
+    match Binding::from_str(name) {
+        Some(binding) => Ok(binding),
+        None => Err(DorpErr::BaseString("Unknown binding: ".to_string() + name)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use input::{InputMap, Binding, Keyboard, Mouse, Button, KeyCode, MouseButton, ButtonState};
+
+    fn jump_map() -> InputMap {
+        let mut input_map = InputMap::new();
+        input_map.bind_action("jump", Binding::Key(KeyCode::Space));
+        input_map.bind_action("jump", Binding::Key(KeyCode::W));
+        input_map
+    }
+
+    #[test]
+    fn second_binding_does_not_press_again() {
+        let input_map = jump_map();
+        let mouse = Mouse::new();
+        let mut keyboard = Keyboard::new();
+        keyboard.set_key_state(KeyCode::Space, Button::new(1, ButtonState::Pressed));
+        assert!(input_map.was_action_just_pressed("jump", 1, &keyboard, &mouse));
+        keyboard.set_key_state(KeyCode::W, Button::new(3, ButtonState::Pressed));
+        assert!(input_map.is_action_pressed("jump", &keyboard, &mouse));
+        assert!(!input_map.was_action_just_pressed("jump", 3, &keyboard, &mouse));
+    }
+
+    #[test]
+    fn bindings_pressed_together_press_once() {
+        let input_map = jump_map();
+        let mouse = Mouse::new();
+        let mut keyboard = Keyboard::new();
+        keyboard.set_key_state(KeyCode::Space, Button::new(2, ButtonState::Pressed));
+        keyboard.set_key_state(KeyCode::W, Button::new(2, ButtonState::Pressed));
+        assert!(input_map.was_action_just_pressed("jump", 2, &keyboard, &mouse));
+    }
+
+    #[test]
+    fn release_waits_for_last_binding() {
+        let input_map = jump_map();
+        let mouse = Mouse::new();
+        let mut keyboard = Keyboard::new();
+        keyboard.set_key_state(KeyCode::Space, Button::new(1, ButtonState::Pressed));
+        keyboard.set_key_state(KeyCode::W, Button::new(2, ButtonState::Pressed));
+        let mut space = keyboard.get_key(KeyCode::Space);
+        space.update(4, ButtonState::Released);
+        keyboard.set_key_state(KeyCode::Space, space);
+        assert!(!input_map.was_action_just_released("jump", 4, &keyboard, &mouse));
+        let mut w = keyboard.get_key(KeyCode::W);
+        w.update(5, ButtonState::Released);
+        keyboard.set_key_state(KeyCode::W, w);
+        assert!(input_map.was_action_just_released("jump", 5, &keyboard, &mouse));
+        assert!(!input_map.is_action_pressed("jump", &keyboard, &mouse));
+    }
+
+    #[test]
+    fn from_yaml_str_reads_actions_and_axes() {
+        let input_map = InputMap::from_yaml_str("actions:\n  jump: [Space, MouseLeft]\n  fire: F\naxes:\n  horizontal:\n    positive: D\n    negative: [A, Left]\n").unwrap();
+        assert_eq!(input_map.get_action_bindings("jump"), Some(&vec!(Binding::Key(KeyCode::Space), Binding::MouseButton(MouseButton::Left))));
+        assert_eq!(input_map.get_action_bindings("fire"), Some(&vec!(Binding::Key(KeyCode::F))));
+        let axis = input_map.get_axis("horizontal").unwrap();
+        assert_eq!(axis.get_positive(), &vec!(Binding::Key(KeyCode::D)));
+        assert_eq!(axis.get_negative(), &vec!(Binding::Key(KeyCode::A), Binding::Key(KeyCode::Left)));
+    }
+
+    #[test]
+    fn from_yaml_str_rejects_unknown_bindings() {
+        assert!(InputMap::from_yaml_str("actions:\n  jump: [Space, NotAKey]\n").is_err());
+        assert!(InputMap::from_yaml_str("").unwrap().get_action_bindings("jump").is_none());
+    }
+}
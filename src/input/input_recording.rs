@@ -0,0 +1,183 @@
+use std::fs::{File};
+use std::io::{Read, Write};
+use std::path::{Path};
+
+use input::{InputEvent};
+use logic::{TickCount};
+use err::DorpErr;
+
+#[derive(Debug, Clone)]
+pub struct InputRecording {
+    events: Vec<(TickCount, InputEvent)>,
+    length: TickCount,
+}
+
+impl InputRecording {
+    pub fn new() -> InputRecording {
+        InputRecording {
+            events: vec!(),
+            length: 0,
+        }
+    }
+
+    pub fn record(&mut self, tick: TickCount, event: InputEvent) {
+        let index = self.find_first_at(tick + 1);
+        self.events.insert(index, (tick, event));
+        if tick >= self.length {
+            self.length = tick + 1;
+        }
+    }
+
+    pub fn set_length(&mut self, length: TickCount) {
+        self.length = length;
+    }
+
+    pub fn get_length(&self) -> TickCount {
+        self.length
+    }
+
+    pub fn get_events(&self) -> &Vec<(TickCount, InputEvent)> {
+        &self.events
+    }
+
+    pub fn get_events_at(&self, tick: TickCount) -> Vec<InputEvent> {
+        let start = self.find_first_at(tick);
+        self.events[start..].iter().take_while(|entry| entry.0 == tick).map(|entry| entry.1.clone()).collect()
+    }
+
+    fn find_first_at(&self, tick: TickCount) -> usize {
+        let mut low = 0;
+        let mut high = self.events.len();
+        while low < high {
+            let middle = (low + high) / 2;
+            if self.events[middle].0 < tick {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), DorpErr> {
+        let mut file = match File::create(path) {
+            Ok(file) => file,
+            Err(err) => return Err(DorpErr::Io("File Create", err)),
+        };
+        let mut text = format!("length {}\n", self.length);
+        for &(tick, ref event) in self.events.iter() {
+            text.push_str(&format!("{} {}\n", tick, event.to_line()));
+        }
+        match file.write_all(text.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(DorpErr::Io("File Write All", err)),
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<InputRecording, DorpErr> {
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => return Err(DorpErr::Io("File Open", err)),
+        };
+        let mut text = String::new();
+        match file.read_to_string(&mut text) {
+            Ok(_) => (),
+            Err(err) => return Err(DorpErr::Io("File Read To String", err)),
+        }
+        let mut recording = InputRecording::new();
+        let mut length = None;
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (head, rest) = match line.find(' ') {
+                Some(index) => (&line[..index], &line[index + 1..]),
+                None => return Err(DorpErr::BaseString("Invalid recording line: ".to_string() + line)),
+            };
+            if head == "length" {
+                match rest.trim().parse::<TickCount>() {
+                    Ok(parsed) => length = Some(parsed),
+                    Err(_) => return Err(DorpErr::BaseString("Invalid recording length: ".to_string() + line)),
+                }
+                continue;
+            }
+            let tick = match head.parse::<TickCount>() {
+                Ok(tick) => tick,
+                Err(_) => return Err(DorpErr::BaseString("Invalid recording tick: ".to_string() + line)),
+            };
+            match InputEvent::from_line(rest) {
+                Ok(event) => recording.record(tick, event),
+                Err(err) => return Err(DorpErr::Dorp("Input Event From Line", Box::new(err))),
+            }
+        }
+        if let Some(length) = length {
+            if length < recording.length {
+                return Err(DorpErr::BaseString(format!("Recording length {} is shorter than its events: {}", length, recording.length)));
+            }
+            recording.length = length;
+        }
+        Ok(recording)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::{temp_dir};
+    use std::fs::{File, remove_file};
+    use std::io::{Write};
+
+    use input::{InputEvent, InputRecording, KeyCode, ButtonState};
+
+    #[test]
+    fn events_stay_grouped_by_tick() {
+        let mut recording = InputRecording::new();
+        recording.record(3, InputEvent::MousePosition(3, 3));
+        recording.record(1, InputEvent::MousePosition(1, 1));
+        recording.record(3, InputEvent::Focused(false));
+        assert_eq!(recording.get_length(), 4);
+        assert_eq!(recording.get_events_at(0), vec!());
+        assert_eq!(recording.get_events_at(1), vec!(InputEvent::MousePosition(1, 1)));
+        assert_eq!(recording.get_events_at(3), vec!(InputEvent::MousePosition(3, 3), InputEvent::Focused(false)));
+    }
+
+    #[test]
+    fn load_derives_missing_length() {
+        let path = temp_dir().join("dorp_recording_without_length.txt");
+        {
+            let mut file = File::create(&path).unwrap();
+            file.write_all(b"0 key A Pressed\n4 key A Released\n").unwrap();
+        }
+        let recording = InputRecording::load(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert_eq!(recording.get_length(), 5);
+        assert_eq!(recording.get_events_at(4), vec!(InputEvent::Key(KeyCode::A, ButtonState::Released)));
+    }
+
+    #[test]
+    fn load_rejects_length_shorter_than_events() {
+        let path = temp_dir().join("dorp_recording_short_length.txt");
+        {
+            let mut file = File::create(&path).unwrap();
+            file.write_all(b"length 2\n0 key A Pressed\n4 key A Released\n").unwrap();
+        }
+        let result = InputRecording::load(&path);
+        remove_file(&path).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut recording = InputRecording::new();
+        recording.record(0, InputEvent::Key(KeyCode::Space, ButtonState::Pressed));
+        recording.record(2, InputEvent::Character('x'));
+        recording.record(2, InputEvent::ScrollLines(0.5, -1.0));
+        recording.set_length(6);
+        let path = temp_dir().join("dorp_recording_round_trip.txt");
+        recording.save(&path).unwrap();
+        let loaded = InputRecording::load(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert_eq!(loaded.get_length(), 6);
+        assert_eq!(loaded.get_events(), recording.get_events());
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::{HashMap};
 
-use input::{Button, KeyCode, ButtonState};
+use input::{Button, KeyCode};
 
 #[derive(Debug)]
 pub struct Keyboard {
@@ -17,7 +17,7 @@ impl Keyboard {
     pub fn get_key(&self, key_code: KeyCode) -> Button {
         match self.keys.get(&key_code) {
             Some(key) => *key,
-            None => Button::new(0, ButtonState::Released),
+            None => Button::never_pressed(),
         }
     }
 
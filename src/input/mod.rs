@@ -2,12 +2,24 @@ mod keyboard;
 mod mouse;
 mod display;
 mod button;
+mod names;
+mod input_event;
+mod input_recording;
+mod input_map;
+mod tick_input;
 
 pub use self::keyboard::{Keyboard};
 pub use self::mouse::{Mouse};
 pub use self::display::{Display};
 pub use self::button::{Button};
+pub use self::names::{key_code_from_str, key_code_to_string, mouse_button_from_str, mouse_button_to_string, button_state_from_str, button_state_to_string, touch_phase_from_str, touch_phase_to_string};
+pub use self::input_event::{InputEvent};
+pub use self::input_recording::{InputRecording};
+pub use self::input_map::{InputMap, Binding, Axis};
+pub use self::tick_input::{TickInput, TouchPoint};
 
 pub use glium::glutin::ElementState as ButtonState;
 pub use glium::glutin::VirtualKeyCode as KeyCode;
 pub use glium::glutin::MouseButton as MouseButton;
+pub use glium::glutin::TouchPhase as TouchPhase;
+pub use glium::glutin::MouseScrollDelta as MouseScrollDelta;
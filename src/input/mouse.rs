@@ -1,12 +1,14 @@
 use std::collections::{HashMap};
 
 use math::{Vec2};
-use input::{Button, MouseButton, ButtonState};
+use input::{Button, MouseButton};
 
 #[derive(Debug)]
 pub struct Mouse {
     buttons: HashMap<MouseButton, Button>,
     position: Vec2,
+    delta: Vec2,
+    has_position: bool,
 }
 
 impl Mouse {
@@ -14,6 +16,8 @@ impl Mouse {
         Mouse {
             buttons: HashMap::new(),
             position: Vec2::zero(),
+            delta: Vec2::zero(),
+            has_position: false,
         }
     }
 
@@ -24,15 +28,57 @@ impl Mouse {
     pub fn get_button(&self, mouse_button: MouseButton) -> Button {
         match self.buttons.get(&mouse_button) {
             Some(button) => *button,
-            None => Button::new(0, ButtonState::Released),
+            None => Button::never_pressed(),
         }
     }
 
     pub fn set_mouse_position(&mut self, pos: Vec2) {
+        if self.has_position {
+            self.delta = self.delta + (pos - self.position);
+        }
         self.position = pos;
+        self.has_position = true;
+    }
+
+    pub fn clear_delta(&mut self) {
+        self.delta = Vec2::zero();
+    }
+
+    pub fn get_mouse_delta(&self) -> Vec2 {
+        self.delta
+    }
+
+    pub fn get_normalized_position(&self, resolution: Vec2) -> Vec2 {
+        if resolution[0] == 0.0 || resolution[1] == 0.0 {
+            return Vec2::zero();
+        }
+        Vec2::from([self.position[0] / resolution[0] * 2.0 - 1.0, 1.0 - self.position[1] / resolution[1] * 2.0])
     }
 
     pub fn get_mouse_position(&self) -> Vec2 {
         self.position
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use input::{Mouse};
+    use math::{Vec2};
+
+    #[test]
+    fn normalized_position_maps_corners() {
+        let mut mouse = Mouse::new();
+        mouse.set_mouse_position(Vec2::from([0.0, 0.0]));
+        assert_eq!(mouse.get_normalized_position(Vec2::from([640.0, 480.0])), Vec2::from([-1.0, 1.0]));
+        mouse.set_mouse_position(Vec2::from([640.0, 480.0]));
+        assert_eq!(mouse.get_normalized_position(Vec2::from([640.0, 480.0])), Vec2::from([1.0, -1.0]));
+    }
+
+    #[test]
+    fn normalized_position_is_zero_without_resolution() {
+        let mut mouse = Mouse::new();
+        mouse.set_mouse_position(Vec2::from([12.0, 34.0]));
+        assert_eq!(mouse.get_normalized_position(Vec2::from([0.0, 480.0])), Vec2::zero());
+        assert_eq!(mouse.get_normalized_position(Vec2::from([640.0, 0.0])), Vec2::zero());
+    }
+}
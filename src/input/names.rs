@@ -0,0 +1,84 @@
+use input::{KeyCode, MouseButton, ButtonState, TouchPhase};
+
+macro_rules! key_codes {
+    ($($name: ident),*) => {
+        pub fn key_code_from_str(name: &str) -> Option<KeyCode> {
+            match name {
+                $(stringify!($name) => Some(KeyCode::$name),)*
+                _ => None,
+            }
+        }
+
+        pub fn key_code_to_string(key_code: KeyCode) -> String {
+            match key_code {
+                $(KeyCode::$name => stringify!($name),)*
+            }.to_string()
+        }
+    }
+}
+
+key_codes!(
+    Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9, Key0,
+    A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z,
+    Escape,
+    F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12, F13, F14, F15,
+    Snapshot, Scroll, Pause, Insert, Home, Delete, End, PageDown, PageUp,
+    Left, Up, Right, Down,
+    Back, Return, Space, Compose,
+    Numlock, Numpad0, Numpad1, Numpad2, Numpad3, Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9,
+    AbntC1, AbntC2, Add, Apostrophe, Apps, At, Ax, Backslash, Calculator, Capital, Colon, Comma, Convert,
+    Decimal, Divide, Equals, Grave, Kana, Kanji, LAlt, LBracket, LControl, LMenu, LShift, LWin,
+    Mail, MediaSelect, MediaStop, Minus, Multiply, Mute, MyComputer, NavigateForward, NavigateBackward,
+    NextTrack, NoConvert, NumpadComma, NumpadEnter, NumpadEquals, OEM102, Period, PlayPause, Power,
+    PrevTrack, RAlt, RBracket, RControl, RMenu, RShift, RWin, Semicolon, Slash, Sleep, Stop, Subtract,
+    Sysrq, Tab, Underline, Unlabeled, VolumeDown, VolumeUp, Wake,
+    WebBack, WebFavorites, WebForward, WebHome, WebRefresh, WebSearch, WebStop, Yen
+);
+
+pub fn mouse_button_from_str(name: &str) -> Option<MouseButton> {
+    match name {
+        "Left" => Some(MouseButton::Left),
+        "Right" => Some(MouseButton::Right),
+        "Middle" => Some(MouseButton::Middle),
+        _ => {
+            if name.starts_with("Other(") && name.ends_with(")") {
+                match name[6..name.len() - 1].parse::<u8>() {
+                    Ok(other) => Some(MouseButton::Other(other)),
+                    Err(_) => None,
+                }
+            } else {
+                None
+            }
+        },
+    }
+}
+
+pub fn mouse_button_to_string(mouse_button: MouseButton) -> String {
+    format!("{:?}", mouse_button)
+}
+
+pub fn button_state_from_str(name: &str) -> Option<ButtonState> {
+    match name {
+        "Pressed" => Some(ButtonState::Pressed),
+        "Released" => Some(ButtonState::Released),
+        _ => None,
+    }
+}
+
+pub fn button_state_to_string(button_state: ButtonState) -> String {
+    format!("{:?}", button_state)
+}
+
+pub fn touch_phase_from_str(name: &str) -> Option<TouchPhase> {
+    match name {
+        "Started" => Some(TouchPhase::Started),
+        "Moved" => Some(TouchPhase::Moved),
+        "Ended" => Some(TouchPhase::Ended),
+        "Cancelled" => Some(TouchPhase::Cancelled),
+        _ => None,
+    }
+}
+
+pub fn touch_phase_to_string(touch_phase: TouchPhase) -> String {
+    format!("{:?}", touch_phase)
+}
@@ -0,0 +1,102 @@
+use std::path::{PathBuf};
+
+use math::{Vec2};
+use input::{TouchPhase};
+
+#[derive(Debug, Copy, Clone)]
+pub struct TouchPoint {
+    id: u64,
+    phase: TouchPhase,
+    location: Vec2,
+}
+
+impl TouchPoint {
+    pub fn new(id: u64, phase: TouchPhase, location: Vec2) -> TouchPoint {
+        TouchPoint {
+            id: id,
+            phase: phase,
+            location: location,
+        }
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn get_phase(&self) -> TouchPhase {
+        self.phase
+    }
+
+    pub fn get_location(&self) -> Vec2 {
+        self.location
+    }
+}
+
+#[derive(Debug)]
+pub struct TickInput {
+    text: String,
+    scroll_lines: Vec2,
+    scroll_pixels: Vec2,
+    dropped_files: Vec<PathBuf>,
+    touches: Vec<TouchPoint>,
+}
+
+impl TickInput {
+    pub fn new() -> TickInput {
+        TickInput {
+            text: String::new(),
+            scroll_lines: Vec2::zero(),
+            scroll_pixels: Vec2::zero(),
+            dropped_files: vec!(),
+            touches: vec!(),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.scroll_lines = Vec2::zero();
+        self.scroll_pixels = Vec2::zero();
+        self.dropped_files.clear();
+        self.touches.clear();
+    }
+
+    pub fn push_character(&mut self, character: char) {
+        self.text.push(character);
+    }
+
+    pub fn add_scroll_lines(&mut self, delta: Vec2) {
+        self.scroll_lines = self.scroll_lines + delta;
+    }
+
+    pub fn add_scroll_pixels(&mut self, delta: Vec2) {
+        self.scroll_pixels = self.scroll_pixels + delta;
+    }
+
+    pub fn push_dropped_file(&mut self, path: PathBuf) {
+        self.dropped_files.push(path);
+    }
+
+    pub fn push_touch(&mut self, touch: TouchPoint) {
+        self.touches.push(touch);
+    }
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn get_scroll_lines(&self) -> Vec2 {
+        self.scroll_lines
+    }
+
+    pub fn get_scroll_pixels(&self) -> Vec2 {
+        self.scroll_pixels
+    }
+
+    pub fn get_dropped_files(&self) -> &Vec<PathBuf> {
+        &self.dropped_files
+    }
+
+    pub fn get_touches(&self) -> &Vec<TouchPoint> {
+        &self.touches
+    }
+}
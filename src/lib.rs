@@ -13,17 +13,28 @@ pub mod graphics;
 mod components;
 mod err;
 
-pub use self::math::{Mat4, Vec2, Vec3, Vec4, DEG_TO_RAD};
-pub use self::input::{Keyboard, Mouse, Display};
-pub use self::logic::{TickCount, IdManager, Game, World, Id, IdType, Entity, OptErr};
+pub use self::math::{Mat4, Vec2, Vec3, Vec4, Quat, Ray, DEG_TO_RAD};
+pub use self::input::{Keyboard, Mouse, Display, Button, ButtonState, KeyCode, MouseButton, InputEvent, InputRecording, InputMap, Binding, Axis, TickInput, TouchPoint, TouchPhase};
+pub use self::logic::{
+    TickCount, IdManager, Game, GameConfig, TickErrorPolicy, GameStats, World, Entity, OptErr, QuitRequest,
+    Id, IdType, IdTypeTrait, IdSize, id_types,
+    EntityId, ComponentId, VertexId, IndexId, TextureId, DrawMethodId, MatrixId, ColorId,
+    Persistent, SNAPSHOT_VERSION, save_snapshot, load_snapshot, snapshot_to_yaml, snapshot_from_yaml, id_to_yaml, id_from_yaml,
+    FileWatcher
+};
 pub use self::graphics::{WindowBuilder, Window, SyncData, Renderers, RendererType};
 pub use self::components::{
     Transform,
     Renderable,
-    RenderableTex2, RenderableSolidColor, RenderableVertexColor,
+    RenderableTex2, RenderableSolidColor, RenderableVertexColor, Mesh,
     Named,
     Map2d, Map2dCoords,
     Map3d, Map3dCoords,
-    Scene
+    Scene, SceneHook, SceneManager, SceneTransition,
+    EntityDesc, EntityRegistry, EntityFactory,
+    HotReload,
+    Prefab, PrefabInstance,
+    Camera, Projection
 };
 pub use self::err::DorpErr;
+pub use yaml_rust::{Yaml};
@@ -1,16 +1,54 @@
 use std::sync::{Arc};
+use yaml_rust::{Yaml};
 
 use logic::{World, Id, IdManager, TickCount};
 use graphics::{Window, SyncData, Renderers};
-use components::{Renderable, Named, Transform};
+use components::{Renderable, Named, Transform, Camera};
 use err::DorpErr;
 
 pub trait Entity<T: Entity<T>> : Send + Sync {
     fn tick(&self, TickCount, f64, Arc<World<T>>) -> Result<(), DorpErr>;
     fn tick_mut(&mut self, TickCount, &mut IdManager, &mut World<T>, &mut SyncData) -> Result<(), DorpErr>;
-    fn render(&mut self, &mut Window, &mut SyncData, &mut Renderers) -> Result<(), DorpErr>;
+    fn render(&mut self, f64, &mut Window, &mut SyncData, &mut Renderers) -> Result<(), DorpErr>;
     fn get_renderable(&self) -> Option<&Box<Renderable>>;
     fn get_named(&self) -> Option<&Box<Named>>;
     fn get_transform(&self) -> Option<&Box<Transform>>;
     fn get_id(&self) -> Id;
+
+    fn get_mut_transform(&mut self) -> Option<&mut Box<Transform>> {
+        None
+    }
+
+    fn get_camera(&self) -> Option<&Box<Camera>> {
+        None
+    }
+
+    fn get_mut_camera(&mut self) -> Option<&mut Box<Camera>> {
+        None
+    }
+
+    fn tick_paused(&mut self, _: TickCount, _: &mut World<T>) -> Result<(), DorpErr> {
+        Ok(())
+    }
+
+    fn on_pause(&mut self, _: &mut World<T>) -> Result<(), DorpErr> {
+        Ok(())
+    }
+
+    fn on_resume(&mut self, _: &mut World<T>) -> Result<(), DorpErr> {
+        Ok(())
+    }
+
+    fn on_shutdown(&mut self, _: &mut World<T>) -> Result<(), DorpErr> {
+        Ok(())
+    }
+
+    // Entities that return None here are left out of snapshots
+    fn persist(&self) -> Option<Yaml> {
+        None
+    }
+
+    fn restore(_: &Yaml, _: &mut IdManager) -> Result<T, DorpErr> {
+        Err(DorpErr::Base("Entity does not restore from snapshots"))
+    }
 }
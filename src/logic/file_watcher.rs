@@ -0,0 +1,108 @@
+use std::collections::{HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime};
+use time::{precise_time_s};
+
+#[derive(Debug)]
+pub struct FileWatcher {
+    files: HashMap<PathBuf, Option<SystemTime>>,
+    poll_interval: f64,
+    last_poll: f64,
+}
+
+impl FileWatcher {
+    pub fn new(poll_interval: f64) -> FileWatcher {
+        FileWatcher {
+            files: HashMap::new(),
+            poll_interval: poll_interval,
+            last_poll: 0.0,
+        }
+    }
+
+    pub fn watch<P: AsRef<Path>>(&mut self, path: P) {
+        let path = path.as_ref().to_path_buf();
+        let modified = FileWatcher::get_modified(&path);
+        self.files.insert(path, modified);
+    }
+
+    pub fn unwatch<P: AsRef<Path>>(&mut self, path: P) {
+        self.files.remove(path.as_ref());
+    }
+
+    pub fn is_watching<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.files.contains_key(path.as_ref())
+    }
+
+    pub fn set_poll_interval(&mut self, poll_interval: f64) {
+        self.poll_interval = poll_interval;
+    }
+
+    pub fn get_poll_interval(&self) -> f64 {
+        self.poll_interval
+    }
+
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let now = precise_time_s();
+        if now - self.last_poll < self.poll_interval {
+            return vec!();
+        }
+        self.last_poll = now;
+        self.poll_now()
+    }
+
+    pub fn poll_now(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec!();
+        for (path, modified) in self.files.iter_mut() {
+            let current = FileWatcher::get_modified(path);
+            if current.is_some() && current != *modified {
+                changed.push(path.clone());
+            }
+            *modified = current;
+        }
+        changed
+    }
+
+    fn get_modified(path: &Path) -> Option<SystemTime> {
+        match fs::metadata(path) {
+            Ok(metadata) => metadata.modified().ok(),
+            Err(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::{temp_dir};
+    use std::fs::{File, remove_file};
+
+    use logic::{FileWatcher};
+
+    #[test]
+    fn created_files_are_reported_once() {
+        let path = temp_dir().join("dorp_file_watcher_created.txt");
+        let _ = remove_file(&path);
+        let mut watcher = FileWatcher::new(0.0);
+        watcher.watch(&path);
+        assert!(watcher.is_watching(&path));
+        assert!(watcher.poll_now().is_empty());
+        File::create(&path).unwrap();
+        assert_eq!(watcher.poll_now(), vec!(path.clone()));
+        assert!(watcher.poll_now().is_empty());
+        remove_file(&path).unwrap();
+        assert!(watcher.poll_now().is_empty());
+    }
+
+    #[test]
+    fn unwatched_files_are_not_reported() {
+        let path = temp_dir().join("dorp_file_watcher_unwatched.txt");
+        let _ = remove_file(&path);
+        let mut watcher = FileWatcher::new(0.0);
+        watcher.watch(&path);
+        watcher.unwatch(&path);
+        assert!(!watcher.is_watching(&path));
+        File::create(&path).unwrap();
+        assert!(watcher.poll().is_empty());
+        remove_file(&path).unwrap();
+    }
+}
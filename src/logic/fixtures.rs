@@ -0,0 +1,195 @@
+use std::sync::{Arc};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use yaml_rust::{Yaml};
+use yaml_rust::yaml::{Hash as YamlHash};
+
+use input::{Keyboard, Mouse, Display};
+use logic::{World, Entity, Id, IdManager, TickCount, Persistent, id_to_yaml, id_from_yaml};
+use graphics::{Window, SyncData, Renderers};
+use graphics::solid_color::{Vertex};
+use components::{EntityDesc, Renderable, RenderableSolidColor, Named, Transform, Camera};
+use math::{Vec2, Vec3};
+use err::DorpErr;
+
+// The one entity every test module builds its worlds from, tests flip the pub fields they care about
+pub struct TestEntity {
+    pub id: Id,
+    pub named: Option<Box<Named>>,
+    pub transform: Option<Box<Transform>>,
+    pub renderable: Option<Box<Renderable>>,
+    pub camera: Option<Box<Camera>>,
+    pub ticks: AtomicUsize,
+    pub mut_ticks: usize,
+    pub paused_ticks: usize,
+    pub resume_after: Option<usize>,
+    pub fails: bool,
+    pub despawns: Vec<Id>,
+    pub persisted: bool,
+}
+
+impl TestEntity {
+    pub fn new(id: Id) -> TestEntity {
+        TestEntity {
+            id: id,
+            named: None,
+            transform: None,
+            renderable: None,
+            camera: None,
+            ticks: AtomicUsize::new(0),
+            mut_ticks: 0,
+            paused_ticks: 0,
+            resume_after: None,
+            fails: false,
+            despawns: vec!(),
+            persisted: true,
+        }
+    }
+
+    pub fn quad(manager: &mut IdManager, position: Vec3) -> TestEntity {
+        let mut solid_color = RenderableSolidColor::new(manager);
+        solid_color.set_vertices(vec!(
+            Vertex::new([-1.0, -1.0, 0.0]),
+            Vertex::new([1.0, -1.0, 0.0]),
+            Vertex::new([1.0, 1.0, 0.0]),
+            Vertex::new([-1.0, 1.0, 0.0]),
+        ));
+        solid_color.set_indices(vec!(0, 1, 2, 0, 2, 3));
+        let mut renderable = Renderable::new();
+        renderable.set_solid_color(solid_color);
+        let mut transform = Transform::new();
+        transform.set_position(position);
+        TestEntity::new(Id::new(manager)).with_renderable(renderable).with_transform(transform)
+    }
+
+    pub fn with_named(mut self, named: Named) -> TestEntity {
+        self.named = Some(Box::new(named));
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> TestEntity {
+        self.transform = Some(Box::new(transform));
+        self
+    }
+
+    pub fn with_renderable(mut self, renderable: Renderable) -> TestEntity {
+        self.renderable = Some(Box::new(renderable));
+        self
+    }
+
+    pub fn with_camera(mut self, camera: Camera) -> TestEntity {
+        self.camera = Some(Box::new(camera));
+        self
+    }
+
+    pub fn get_counts(&self) -> (usize, usize, usize) {
+        (self.ticks.load(Ordering::SeqCst), self.mut_ticks, self.paused_ticks)
+    }
+}
+
+impl Entity<TestEntity> for TestEntity {
+    fn tick(&self, _: TickCount, _: f64, _: Arc<World<TestEntity>>) -> Result<(), DorpErr> {
+        self.ticks.fetch_add(1, Ordering::SeqCst);
+        if self.fails {
+            return Err(DorpErr::Base("Test Entity Tick failed"));
+        }
+        Ok(())
+    }
+
+    fn tick_mut(&mut self, _: TickCount, _: &mut IdManager, world: &mut World<TestEntity>, _: &mut SyncData) -> Result<(), DorpErr> {
+        self.mut_ticks += 1;
+        for id in self.despawns.drain(..) {
+            world.despawn_entity(id);
+        }
+        Ok(())
+    }
+
+    fn tick_paused(&mut self, _: TickCount, world: &mut World<TestEntity>) -> Result<(), DorpErr> {
+        self.paused_ticks += 1;
+        if self.resume_after == Some(self.paused_ticks) {
+            world.request_resume();
+        }
+        Ok(())
+    }
+
+    fn render(&mut self, _: f64, _: &mut Window, _: &mut SyncData, _: &mut Renderers) -> Result<(), DorpErr> {
+        Ok(())
+    }
+
+    fn get_renderable(&self) -> Option<&Box<Renderable>> {
+        self.renderable.as_ref()
+    }
+
+    fn get_named(&self) -> Option<&Box<Named>> {
+        self.named.as_ref()
+    }
+
+    fn get_transform(&self) -> Option<&Box<Transform>> {
+        self.transform.as_ref()
+    }
+
+    fn get_mut_transform(&mut self) -> Option<&mut Box<Transform>> {
+        self.transform.as_mut()
+    }
+
+    fn get_camera(&self) -> Option<&Box<Camera>> {
+        self.camera.as_ref()
+    }
+
+    fn get_mut_camera(&mut self) -> Option<&mut Box<Camera>> {
+        self.camera.as_mut()
+    }
+
+    fn get_id(&self) -> Id {
+        self.id
+    }
+
+    fn persist(&self) -> Option<Yaml> {
+        if !self.persisted {
+            return None;
+        }
+        let mut hash = YamlHash::new();
+        hash.insert(Yaml::String("id".to_string()), id_to_yaml(self.id));
+        if let Some(ref named) = self.named {
+            hash.insert(Yaml::String("named".to_string()), named.save());
+        }
+        if self.camera.is_some() {
+            hash.insert(Yaml::String("camera".to_string()), Yaml::Boolean(true));
+        }
+        Some(Yaml::Hash(hash))
+    }
+
+    fn restore(yaml: &Yaml, manager: &mut IdManager) -> Result<TestEntity, DorpErr> {
+        let mut entity = match id_from_yaml(&yaml["id"]) {
+            Ok(id) => TestEntity::new(id),
+            Err(err) => return Err(DorpErr::Dorp("Id From Yaml", Box::new(err))),
+        };
+        match yaml["named"] {
+            Yaml::BadValue => (),
+            ref named => match Named::load(named, manager) {
+                Ok(named) => entity.named = Some(Box::new(named)),
+                Err(err) => return Err(DorpErr::Dorp("Named Load", Box::new(err))),
+            },
+        }
+        if yaml["camera"].as_bool() == Some(true) {
+            entity.camera = Some(Box::new(Camera::new_screen(Vec2::from([640.0, 480.0]))));
+        }
+        Ok(entity)
+    }
+}
+
+pub fn test_world() -> World<TestEntity> {
+    World::new(Keyboard::new(), Mouse::new(), Display::new(Vec2::from([640.0, 480.0])))
+}
+
+pub fn build_test_entity(desc: &EntityDesc, manager: &mut IdManager, world: &mut World<TestEntity>) -> Result<TestEntity, DorpErr> {
+    let id = Id::new(manager);
+    let (transform, renderable, named) = match desc.build_parts(id, manager, world) {
+        Ok(parts) => parts,
+        Err(err) => return Err(DorpErr::Dorp("Desc Build Parts", Box::new(err))),
+    };
+    let mut entity = TestEntity::new(id);
+    entity.named = named.map(Box::new);
+    entity.transform = transform.map(Box::new);
+    entity.renderable = renderable.map(Box::new);
+    Ok(entity)
+}
@@ -1,28 +1,58 @@
-use std::sync::{Arc};
+use std::sync::{Arc, Mutex};
+use std::mem;
+use std::cmp::{max};
+use std::path::{PathBuf};
+use std::thread::{sleep};
+use std::time::{Duration};
 use scoped_threadpool::{Pool};
 use time::{precise_time_s};
 use glium::glutin::Event as WindowEvent;
 
-use input::{Keyboard, Mouse, Display, KeyCode, ButtonState, MouseButton, Button};
-use logic::{TickCount, World, Entity, IdManager};
+use input::{Keyboard, Mouse, Display, KeyCode, ButtonState, MouseButton, InputEvent, InputRecording, MouseScrollDelta, TouchPoint};
+use logic::{TickCount, World, Entity, IdManager, GameConfig, TickErrorPolicy, GameStats, Id};
 use math::{Vec2};
 use graphics::{Window, SyncData, Renderers};
+use components::{SceneManager, HotReload};
 use err::{DorpErr};
 
+const MAX_TICK_ERRORS: usize = 64;
+
 pub struct Game<T: Entity<T>> {
     world: Arc<World<T>>,
     sync_data: Arc<SyncData>,
     thread_pool: Pool,
     tick_count: TickCount,
+    step_count: TickCount,
+    config: GameConfig,
+    dropped_time: f64,
+    tick_errors: Vec<(Id, DorpErr)>,
+    dropped_tick_errors: u64,
+    stats_callback: Option<Box<Fn(&GameStats)>>,
+    tick_error_callback: Option<Box<Fn(Id, &DorpErr)>>,
+    paused_by_focus: bool,
+    recording: Option<(TickCount, InputRecording)>,
+    scenes: SceneManager<T>,
+    hot_reload: Option<HotReload>,
 }
 
 impl<T: Entity<T>> Game<T> {
-    pub fn new(thread_count: u32, resolution: Vec2) -> Game<T> {
+    pub fn new(thread_count: u32, resolution: Vec2, config: GameConfig) -> Game<T> {
         Game {
             world: Arc::new(World::new(Keyboard::new(), Mouse::new(), Display::new(resolution))),
             sync_data: Arc::new(SyncData::new()),
             thread_pool: Pool::new(thread_count),
             tick_count: 0,
+            step_count: 0,
+            config: config,
+            dropped_time: 0.0,
+            tick_errors: vec!(),
+            dropped_tick_errors: 0,
+            stats_callback: None,
+            tick_error_callback: None,
+            paused_by_focus: false,
+            recording: None,
+            scenes: SceneManager::new(),
+            hot_reload: None,
         }
     }
 
@@ -37,31 +67,264 @@ impl<T: Entity<T>> Game<T> {
         }
     }
 
-    fn pause(&mut self) {
-        println!("Paused");
+    pub fn get_tick_count(&self) -> TickCount {
+        self.tick_count
+    }
+
+    pub fn get_config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    pub fn get_dropped_time(&self) -> f64 {
+        self.dropped_time
+    }
+
+    pub fn take_tick_errors(&mut self) -> Vec<(Id, DorpErr)> {
+        mem::replace(&mut self.tick_errors, vec!())
+    }
+
+    pub fn get_dropped_tick_errors(&self) -> u64 {
+        self.dropped_tick_errors
+    }
+
+    pub fn get_scenes(&self) -> &SceneManager<T> {
+        &self.scenes
+    }
+
+    pub fn get_mut_scenes(&mut self) -> &mut SceneManager<T> {
+        &mut self.scenes
+    }
+
+    // Polled after every tick's tick_mut pass, scene files reload through get_mut_scenes
+    pub fn set_hot_reload(&mut self, hot_reload: Option<HotReload>) {
+        self.hot_reload = hot_reload;
+    }
+
+    pub fn get_hot_reload(&self) -> Option<&HotReload> {
+        self.hot_reload.as_ref()
     }
 
-    fn resume(&mut self) {
-        println!("Resumed");
+    pub fn get_mut_hot_reload(&mut self) -> Option<&mut HotReload> {
+        self.hot_reload.as_mut()
     }
 
-    fn update_keyboard(&mut self, tick_number: u64, key_code: KeyCode, element_state: ButtonState) -> Result<(), DorpErr> {
+    pub fn set_stats_callback(&mut self, stats_callback: Option<Box<Fn(&GameStats)>>) {
+        self.stats_callback = stats_callback;
+    }
+
+    pub fn set_tick_error_callback(&mut self, tick_error_callback: Option<Box<Fn(Id, &DorpErr)>>) {
+        self.tick_error_callback = tick_error_callback;
+    }
+
+    // run and run_headless hand each tick's errors to the callback, or buffer them for take_tick_errors and count what overflows
+    fn keep_tick_errors(&mut self, errors: Vec<(Id, DorpErr)>) {
+        if let Some(ref tick_error_callback) = self.tick_error_callback {
+            for &(id, ref err) in errors.iter() {
+                tick_error_callback(id, err);
+            }
+            return;
+        }
+        self.tick_errors.extend(errors);
+        if self.tick_errors.len() > MAX_TICK_ERRORS {
+            let overflow = self.tick_errors.len() - MAX_TICK_ERRORS;
+            self.tick_errors.drain(0..overflow);
+            self.dropped_tick_errors += overflow as u64;
+        }
+    }
+
+    fn report_rates(&mut self, frames: u64, ticks: u64, elapsed: f64) -> Result<(), DorpErr> {
+        let world = match Arc::get_mut(&mut self.world) {
+            Some(world) => world,
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        };
+        world.get_mut_stats().set_rates(frames as f64 / elapsed, ticks as f64 / elapsed);
+        if let Some(ref stats_callback) = self.stats_callback {
+            stats_callback(world.get_stats());
+        }
+        Ok(())
+    }
+
+    fn drop_backlog(&mut self, delta_time: f64) -> f64 {
+        if delta_time > 0.0 {
+            self.dropped_time += delta_time;
+        }
+        0.0
+    }
+
+    fn limit_frame_rate(&self, frame_start: f64) {
+        if let Some(frame_rate_cap) = self.config.get_frame_rate_cap() {
+            let remaining = 1.0 / frame_rate_cap - (precise_time_s() - frame_start);
+            if remaining > 0.0 {
+                sleep(Duration::new(remaining as u64, (remaining.fract() * 1_000_000_000.0) as u32));
+            }
+        }
+    }
+
+    pub fn update_focus(&mut self, focused: bool) -> Result<(), DorpErr> {
+        self.record(InputEvent::Focused(focused));
+        let tick_while_unfocused = self.config.get_tick_while_unfocused();
+        {
+            let world = match Arc::get_mut(&mut self.world) {
+                Some(world) => world,
+                None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+            };
+            world.set_focused(focused);
+            if !tick_while_unfocused {
+                if !focused && !world.is_paused() {
+                    world.request_pause();
+                    self.paused_by_focus = true;
+                } else if focused && self.paused_by_focus {
+                    world.request_resume();
+                    self.paused_by_focus = false;
+                }
+            }
+        }
+        self.apply_pause_request()
+    }
+
+    fn apply_pause_request(&mut self) -> Result<(), DorpErr> {
+        let world = match Arc::get_mut(&mut self.world) {
+            Some(world) => world,
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        };
+        let paused = match world.take_pause_request() {
+            Some(paused) => paused,
+            None => return Ok(()),
+        };
+        if paused == world.is_paused() {
+            return Ok(());
+        }
+        if !paused {
+            self.paused_by_focus = false;
+        }
+        world.set_paused(paused);
+        if paused {
+            match world.for_each_entity_mut(|entity, world| entity.on_pause(world)) {
+                Ok(()) => Ok(()),
+                Err(err) => Err(DorpErr::Dorp("World For Each Entity Mut On Pause", Box::new(err))),
+            }
+        } else {
+            match world.for_each_entity_mut(|entity, world| entity.on_resume(world)) {
+                Ok(()) => Ok(()),
+                Err(err) => Err(DorpErr::Dorp("World For Each Entity Mut On Resume", Box::new(err))),
+            }
+        }
+    }
+
+    fn is_quit_requested(&self) -> bool {
+        self.world.get_quit_request().is_some()
+    }
+
+    fn shutdown(&mut self) -> Result<(), DorpErr> {
+        let world = match Arc::get_mut(&mut self.world) {
+            Some(world) => world,
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        };
+        match world.for_each_entity_mut(|entity, world| entity.on_shutdown(world)) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(DorpErr::Dorp("World For Each Entity Mut On Shutdown", Box::new(err))),
+        }
+    }
+
+    pub fn get_step_count(&self) -> TickCount {
+        self.step_count
+    }
+
+    pub fn start_recording(&mut self) {
+        self.recording = Some((self.step_count, InputRecording::new()));
+    }
+
+    pub fn stop_recording(&mut self) -> Option<InputRecording> {
+        match self.recording.take() {
+            Some((start, mut recording)) => {
+                let length = max(recording.get_length(), self.step_count - start);
+                recording.set_length(length);
+                Some(recording)
+            },
+            None => None,
+        }
+    }
+
+    fn record(&mut self, event: InputEvent) {
+        let step_count = self.step_count;
+        if let Some((start, ref mut recording)) = self.recording {
+            recording.record(step_count - start, event);
+        }
+    }
+
+    pub fn apply_input_event(&mut self, event: InputEvent) -> Result<(), DorpErr> {
+        let tick_count = self.tick_count;
+        match event {
+            InputEvent::Key(key_code, state) => self.update_keyboard(tick_count, key_code, state),
+            InputEvent::MouseButton(mouse_button, state) => self.update_mouse_button(tick_count, mouse_button, state),
+            InputEvent::MousePosition(x, y) => self.update_mouse_pos((x, y)),
+            InputEvent::Resolution(width, height) => self.update_resolution((width, height)),
+            InputEvent::Focused(focused) => self.update_focus(focused),
+            InputEvent::Character(character) => self.update_received_character(character),
+            InputEvent::ScrollLines(x, y) => self.update_mouse_wheel(MouseScrollDelta::LineDelta(x, y)),
+            InputEvent::ScrollPixels(x, y) => self.update_mouse_wheel(MouseScrollDelta::PixelDelta(x, y)),
+            InputEvent::DroppedFile(path) => self.update_dropped_file(path),
+            InputEvent::Touch(id, phase, x, y) => self.update_touch(TouchPoint::new(id, phase, Vec2::from([x, y]))),
+        }
+    }
+
+    pub fn replay(&mut self, recording: &InputRecording, manager: &mut IdManager) -> Result<Vec<(Id, DorpErr)>, DorpErr> {
+        let events = recording.get_events();
+        let mut tick_errors = vec!();
+        let mut cursor = 0;
+        for step in 0..recording.get_length() {
+            while cursor < events.len() && events[cursor].0 == step {
+                match self.apply_input_event(events[cursor].1.clone()) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Self Apply Input Event", Box::new(err))),
+                }
+                cursor += 1;
+            }
+            match self.step(1, manager) {
+                Ok(errors) => tick_errors.extend(errors),
+                Err(err) => return Err(DorpErr::Dorp("Self Step", Box::new(err))),
+            }
+            if self.is_quit_requested() {
+                break;
+            }
+        }
+        Ok(tick_errors)
+    }
+
+    pub fn update_keyboard(&mut self, tick_number: u64, key_code: KeyCode, element_state: ButtonState) -> Result<(), DorpErr> {
+        if element_state == ButtonState::Pressed && self.world.get_key(key_code).is_pressed() {
+            return Ok(());
+        }
+        self.record(InputEvent::Key(key_code, element_state));
         match Arc::get_mut(&mut self.world) {
-            Some(world) => world.set_key(key_code, Button::new(tick_number, element_state)),
+            Some(world) => {
+                let mut key = world.get_key(key_code);
+                key.update(tick_number, element_state);
+                world.set_key(key_code, key);
+            },
             None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
         }
         Ok(())
     }
 
-    fn update_mouse_button(&mut self, tick_number: u64, mouse_button: MouseButton, element_state: ButtonState) -> Result<(), DorpErr> {
+    pub fn update_mouse_button(&mut self, tick_number: u64, mouse_button: MouseButton, element_state: ButtonState) -> Result<(), DorpErr> {
+        if element_state == ButtonState::Pressed && self.world.get_mouse_button(mouse_button).is_pressed() {
+            return Ok(());
+        }
+        self.record(InputEvent::MouseButton(mouse_button, element_state));
         match Arc::get_mut(&mut self.world) {
-            Some(world) => world.set_mouse_button(mouse_button, Button::new(tick_number, element_state)),
+            Some(world) => {
+                let mut button = world.get_mouse_button(mouse_button);
+                button.update(tick_number, element_state);
+                world.set_mouse_button(mouse_button, button);
+            },
             None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
         }
         Ok(())
     }
 
-    fn update_mouse_pos(&mut self, mouse_pos: (i32, i32)) -> Result<(), DorpErr> {
+    pub fn update_mouse_pos(&mut self, mouse_pos: (i32, i32)) -> Result<(), DorpErr> {
+        self.record(InputEvent::MousePosition(mouse_pos.0, mouse_pos.1));
         match Arc::get_mut(&mut self.world) {
             Some(world) => world.set_mouse_position(Vec2::from([mouse_pos.0 as f32, mouse_pos.1 as f32])),
             None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
@@ -69,7 +332,51 @@ impl<T: Entity<T>> Game<T> {
         Ok(())
     }
 
-    fn update_resolution(&mut self, resolution: (u32, u32)) -> Result<(), DorpErr> {
+    pub fn update_received_character(&mut self, character: char) -> Result<(), DorpErr> {
+        self.record(InputEvent::Character(character));
+        match Arc::get_mut(&mut self.world) {
+            Some(world) => world.push_character(character),
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        }
+        Ok(())
+    }
+
+    pub fn update_mouse_wheel(&mut self, delta: MouseScrollDelta) -> Result<(), DorpErr> {
+        self.record(match delta {
+            MouseScrollDelta::LineDelta(x, y) => InputEvent::ScrollLines(x, y),
+            MouseScrollDelta::PixelDelta(x, y) => InputEvent::ScrollPixels(x, y),
+        });
+        match Arc::get_mut(&mut self.world) {
+            Some(world) => match delta {
+                MouseScrollDelta::LineDelta(x, y) => world.add_scroll_lines(Vec2::from([x, y])),
+                MouseScrollDelta::PixelDelta(x, y) => world.add_scroll_pixels(Vec2::from([x, y])),
+            },
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        }
+        Ok(())
+    }
+
+    pub fn update_dropped_file(&mut self, path: PathBuf) -> Result<(), DorpErr> {
+        self.record(InputEvent::DroppedFile(path.clone()));
+        match Arc::get_mut(&mut self.world) {
+            Some(world) => world.push_dropped_file(path),
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        }
+        Ok(())
+    }
+
+    pub fn update_touch(&mut self, touch: TouchPoint) -> Result<(), DorpErr> {
+        let location = touch.get_location();
+        self.record(InputEvent::Touch(touch.get_id(), touch.get_phase(), location[0], location[1]));
+        match Arc::get_mut(&mut self.world) {
+            Some(world) => world.push_touch(touch),
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        }
+        Ok(())
+    }
+
+    pub fn update_resolution(&mut self, resolution: (u32, u32)) -> Result<(), DorpErr> {
+        self.record(InputEvent::Resolution(resolution.0, resolution.1));
         match Arc::get_mut(&mut self.world) {
             Some(world) => world.set_resolution(Vec2::from([resolution.0 as f32, resolution.1 as f32])),
             None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
@@ -78,13 +385,17 @@ impl<T: Entity<T>> Game<T> {
     }
 
     pub fn run(&mut self, window: &mut Window, manager: &mut IdManager) -> Result<(), DorpErr> {
+        match self.config.validate() {
+            Ok(()) => (),
+            Err(err) => return Err(DorpErr::Dorp("Self Config Validate", Box::new(err))),
+        }
         let mut renderers = match Renderers::new(window) {
             Ok(renderers) => renderers,
             Err(err) => return Err(DorpErr::Dorp("Renderers New", Box::new(err))),
         };
 
-        let tps: f64 = 60.0;
-        let tps_s: f64 = 1.0 / tps;
+        let tps_s: f64 = self.config.get_tick_delta();
+        let max_ticks_per_frame = self.config.get_max_ticks_per_frame();
 
         let mut last_time: f64 = precise_time_s();
         let mut delta_time: f64 = 0.0;
@@ -94,14 +405,18 @@ impl<T: Entity<T>> Game<T> {
         let mut frames: u64 = 0;
         let mut ticks: u64 = 0;
 
-        let mut tick_number: u64 = 0;
-
         loop {
             let now = precise_time_s();
             delta_time += now - last_time;
             last_time = now;
+            let mut frame_ticks: u32 = 0;
             while delta_time > 0.0 {
+                if frame_ticks >= max_ticks_per_frame {
+                    delta_time = self.drop_backlog(delta_time);
+                    break;
+                }
                 for event in window.poll_events(){
+                    let tick_count = self.tick_count;
                     match event {
                         WindowEvent::Resized(width, height) => match self.update_resolution((width, height)) {
                             Ok(()) => (),
@@ -110,22 +425,24 @@ impl<T: Entity<T>> Game<T> {
                         // WindowEvent::Moved(x, y) => {
                         //
                         // },
-                        WindowEvent::Closed => return Ok(()),
-                        // WindowEvent::DroppedFile(path_buffer) => {
-                        //
-                        // },
-                        // WindowEvent::ReceivedCharacter(character) => {
-                        //
-                        // },
-                        WindowEvent::Focused(focused) => {
-                            if focused {
-                                self.resume();
-                            } else {
-                                self.pause();
-                            }
+                        WindowEvent::Closed => return match self.shutdown() {
+                            Ok(()) => Ok(()),
+                            Err(err) => Err(DorpErr::Dorp("Self Shutdown", Box::new(err))),
+                        },
+                        WindowEvent::DroppedFile(path_buffer) => match self.update_dropped_file(path_buffer) {
+                            Ok(()) => (),
+                            Err(err) => return Err(DorpErr::Dorp("Self Update Dropped File", Box::new(err))),
+                        },
+                        WindowEvent::ReceivedCharacter(character) => match self.update_received_character(character) {
+                            Ok(()) => (),
+                            Err(err) => return Err(DorpErr::Dorp("Self Update Received Character", Box::new(err))),
+                        },
+                        WindowEvent::Focused(focused) => match self.update_focus(focused) {
+                            Ok(()) => (),
+                            Err(err) => return Err(DorpErr::Dorp("Self Update Focus", Box::new(err))),
                         },
                         WindowEvent::KeyboardInput(element_state, _, virtual_key_code) => match virtual_key_code {
-                            Some(virtual_key_code) => match self.update_keyboard(tick_number, virtual_key_code, element_state) {
+                            Some(virtual_key_code) => match self.update_keyboard(tick_count, virtual_key_code, element_state) {
                                 Ok(()) => (),
                                 Err(err) => return Err(DorpErr::Dorp("Self Update Keyboard", Box::new(err))),
                             },
@@ -135,10 +452,11 @@ impl<T: Entity<T>> Game<T> {
                             Ok(()) => (),
                             Err(err) => return Err(DorpErr::Dorp("Self Update Mouse Pos", Box::new(err))),
                         },
-                        // WindowEvent::MouseWheel(mouse_scroll_data) => {
-                        //
-                        // },
-                        WindowEvent::MouseInput(element_state, mouse_button) => match self.update_mouse_button(tick_number, mouse_button, element_state) {
+                        WindowEvent::MouseWheel(mouse_scroll_data) => match self.update_mouse_wheel(mouse_scroll_data) {
+                            Ok(()) => (),
+                            Err(err) => return Err(DorpErr::Dorp("Self Update Mouse Wheel", Box::new(err))),
+                        },
+                        WindowEvent::MouseInput(element_state, mouse_button) => match self.update_mouse_button(tick_count, mouse_button, element_state) {
                             Ok(()) => (),
                             Err(err) => return Err(DorpErr::Dorp("Self Update Mouse Button", Box::new(err))),
                         },
@@ -151,42 +469,142 @@ impl<T: Entity<T>> Game<T> {
                         // WindowEvent::Suspended(suspended) => {
                         //
                         // },
-                        // WindowEvent::Touch(touch) => {
-                        //
-                        // },
+                        WindowEvent::Touch(touch) => match self.update_touch(TouchPoint::new(touch.id, touch.phase, Vec2::from([touch.location.0 as f32, touch.location.1 as f32]))) {
+                            Ok(()) => (),
+                            Err(err) => return Err(DorpErr::Dorp("Self Update Touch", Box::new(err))),
+                        },
                         _ => (),
                     }
                 }
+                delta_time -= tps_s;
                 match self.tick(tps_s, manager) {
-                    Ok(()) => (),
+                    Ok(errors) => self.keep_tick_errors(errors),
                     Err(err) => return Err(DorpErr::Dorp("Self Tick", Box::new(err))),
                 };
-                delta_time -= tps_s;
+                if self.is_quit_requested() {
+                    return match self.shutdown() {
+                        Ok(()) => Ok(()),
+                        Err(err) => Err(DorpErr::Dorp("Self Shutdown", Box::new(err))),
+                    };
+                }
                 ticks += 1;
-                tick_number += 1;
+                frame_ticks += 1;
             }
-            renderers = match self.render(window, renderers) {
+            let alpha = 1.0 + delta_time / tps_s;
+            renderers = match self.render(alpha, window, renderers) {
                 Ok(renderers) => renderers,
                 Err(err) => return Err(DorpErr::Dorp("Self Render", Box::new(err))),
             };
             frames += 1;
+            self.limit_frame_rate(now);
             if now > i + 1.0 {
-                i += 1.0;
-                println!("Frames: {} Ticks: {}", frames.to_string(), ticks.to_string());
+                match self.report_rates(frames, ticks, now - i) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Self Report Rates", Box::new(err))),
+                }
+                i = now;
                 frames = 0;
                 ticks = 0;
             }
         }
     }
 
-    fn render(&mut self, window: &mut Window, renderers: Renderers) -> Result<Renderers, DorpErr> {
+    pub fn step(&mut self, ticks: u64, manager: &mut IdManager) -> Result<Vec<(Id, DorpErr)>, DorpErr> {
+        match self.config.validate() {
+            Ok(()) => (),
+            Err(err) => return Err(DorpErr::Dorp("Self Config Validate", Box::new(err))),
+        }
+        let tps_s: f64 = self.config.get_tick_delta();
+        let mut tick_errors = vec!();
+        for _ in 0..ticks {
+            match self.tick(tps_s, manager) {
+                Ok(errors) => tick_errors.extend(errors),
+                Err(err) => return Err(DorpErr::Dorp("Self Tick", Box::new(err))),
+            }
+            if self.is_quit_requested() {
+                return match self.shutdown() {
+                    Ok(()) => Ok(tick_errors),
+                    Err(err) => Err(DorpErr::Dorp("Self Shutdown", Box::new(err))),
+                };
+            }
+        }
+        Ok(tick_errors)
+    }
+
+    pub fn run_headless(&mut self, manager: &mut IdManager, tick_limit: Option<u64>) -> Result<(), DorpErr> {
+        match self.config.validate() {
+            Ok(()) => (),
+            Err(err) => return Err(DorpErr::Dorp("Self Config Validate", Box::new(err))),
+        }
+        let tps_s: f64 = self.config.get_tick_delta();
+        let max_ticks_per_frame = self.config.get_max_ticks_per_frame();
+
+        let mut last_time: f64 = precise_time_s();
+        let mut delta_time: f64 = 0.0;
+
+        let mut ticks: u64 = 0;
+
+        let mut i: f64 = last_time;
+        let mut second_ticks: u64 = 0;
+
+        loop {
+            let now = precise_time_s();
+            if now > i + 1.0 {
+                match self.report_rates(0, second_ticks, now - i) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Self Report Rates", Box::new(err))),
+                }
+                i = now;
+                second_ticks = 0;
+            }
+            delta_time += now - last_time;
+            last_time = now;
+            let mut frame_ticks: u32 = 0;
+            while delta_time > 0.0 {
+                if frame_ticks >= max_ticks_per_frame {
+                    delta_time = self.drop_backlog(delta_time);
+                    break;
+                }
+                if let Some(tick_limit) = tick_limit {
+                    if ticks >= tick_limit {
+                        return Ok(());
+                    }
+                }
+                match self.tick(tps_s, manager) {
+                    Ok(errors) => self.keep_tick_errors(errors),
+                    Err(err) => return Err(DorpErr::Dorp("Self Tick", Box::new(err))),
+                }
+                if self.is_quit_requested() {
+                    return match self.shutdown() {
+                        Ok(()) => Ok(()),
+                        Err(err) => Err(DorpErr::Dorp("Self Shutdown", Box::new(err))),
+                    };
+                }
+                delta_time -= tps_s;
+                ticks += 1;
+                second_ticks += 1;
+                frame_ticks += 1;
+            }
+            let remaining = -delta_time;
+            if remaining > 0.0 {
+                sleep(Duration::new(remaining as u64, (remaining.fract() * 1_000_000_000.0) as u32));
+            }
+        }
+    }
+
+    fn render(&mut self, alpha: f64, window: &mut Window, renderers: Renderers) -> Result<Renderers, DorpErr> {
         let mut renderers = renderers;
         let mut world = match Arc::get_mut(&mut self.world) {
             Some(world) => world,
             None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
         };
+        let render_start = precise_time_s();
+        if let Some(ref mut hot_reload) = self.hot_reload {
+            hot_reload.render(window, &mut renderers);
+        }
+        world.update_render_transforms(alpha);
         for (_, entity) in world.get_mut_entities().iter_mut() {
-            match entity.render(window, match Arc::get_mut(&mut self.sync_data) {
+            match entity.render(alpha, window, match Arc::get_mut(&mut self.sync_data) {
                     Some(sync_data) => sync_data,
                     None => return Err(DorpErr::Base("Arc Get Mut Self Matrix Data was none")),
                 }, &mut renderers) {
@@ -194,7 +612,10 @@ impl<T: Entity<T>> Game<T> {
                 Err(err) => return Err(DorpErr::Dorp("Entity Render", Box::new(err))),
             }
         }
-        world.tick_mut();
+        match world.get_camera_matrices(alpha) {
+            Some((perspective, view)) => renderers.bind_camera(perspective, view),
+            None => renderers.unbind_camera(),
+        }
         let mut frame = window.frame(renderers);
         for entry in world.get_mut_entities().iter() {
             match frame.draw_entity(entry.1, self.sync_data.as_ref()) {
@@ -208,41 +629,125 @@ impl<T: Entity<T>> Game<T> {
                 },
             };
         }
-        match frame.end() {
-            Ok(renderers) => Ok(renderers),
-            Err(err) => Err(DorpErr::Dorp("Frame End", Box::new(err))),
+        let frame_end_start = precise_time_s();
+        let renderers = match frame.end() {
+            Ok(renderers) => renderers,
+            Err(err) => return Err(DorpErr::Dorp("Frame End", Box::new(err))),
+        };
+        let frame_end_end = precise_time_s();
+        world.get_mut_stats().set_render_durations(frame_end_start - render_start, frame_end_end - frame_end_start);
+        Ok(renderers)
+    }
+
+    fn tick_paused(&mut self) -> Result<(), DorpErr> {
+        let tick_count = self.tick_count;
+        match Arc::get_mut(&mut self.world) {
+            Some(world) => {
+                match world.for_each_entity_mut(|entity, world| entity.tick_paused(tick_count, world)) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("World For Each Entity Mut Tick Paused", Box::new(err))),
+                }
+                world.tick_mut();
+                world.clear_tick_input();
+            },
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        }
+        match self.apply_pause_request() {
+            Ok(()) => Ok(()),
+            Err(err) => Err(DorpErr::Dorp("Self Apply Pause Request", Box::new(err))),
         }
     }
 
-    fn tick(&mut self, delta_time: f64, manager: &mut IdManager) -> Result<(), DorpErr> {
+    fn tick(&mut self, delta_time: f64, manager: &mut IdManager) -> Result<Vec<(Id, DorpErr)>, DorpErr> {
+        self.step_count += 1;
+        match Arc::get_mut(&mut self.world) {
+            Some(world) => {
+                for id in world.take_dead_ids() {
+                    manager.free_id(id);
+                }
+                for (_, entity) in world.get_mut_entities().iter_mut() {
+                    if let Some(transform) = entity.get_mut_transform() {
+                        transform.tick_mut();
+                    }
+                }
+            },
+            None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
+        }
+        if self.world.is_paused() {
+            return match self.tick_paused() {
+                Ok(()) => Ok(vec!()),
+                Err(err) => Err(DorpErr::Dorp("Self Tick Paused", Box::new(err))),
+            };
+        }
+        let errors: Mutex<Vec<(Id, DorpErr)>> = Mutex::new(vec!());
+        let durations: Mutex<Vec<(Id, f64)>> = Mutex::new(vec!());
+        let parallel_tick_start = precise_time_s();
         {
             let world = self.world.clone();
             let tick_count = self.tick_count;
+            let errors = &errors;
+            let durations = &durations;
             self.thread_pool.scoped(|scope| {
                 for entry in world.get_entities().iter() {
+                    let id = *entry.0;
                     let entity = entry.1.clone();
                     let world = world.clone();
                     let delta_time = delta_time.clone();
                     let tick_count = tick_count.clone();
                     scope.execute(move || {
+                        let entity_start = precise_time_s();
                         match entity.tick(tick_count, delta_time, world) {
                             Ok(()) => (),
-                            Err(err) => Err(("Entity Tick", err)).unwrap(),
+                            Err(err) => match errors.lock() {
+                                Ok(mut errors) => errors.push((id, err)),
+                                Err(poisoned) => poisoned.into_inner().push((id, err)),
+                            },
+                        }
+                        if let Ok(mut durations) = durations.lock() {
+                            durations.push((id, precise_time_s() - entity_start));
                         }
                     });
                 }
             });
         }
+        let parallel_tick_end = precise_time_s();
+        let errors = match errors.into_inner() {
+            Ok(errors) => errors,
+            Err(_) => return Err(DorpErr::Base("Errors Into Inner was poisoned")),
+        };
+        let durations = match durations.into_inner() {
+            Ok(durations) => durations,
+            Err(_) => return Err(DorpErr::Base("Durations Into Inner was poisoned")),
+        };
+        let mut tick_errors = vec!();
+        let mut removed = vec!();
         match Arc::get_mut(&mut self.world)  {
             Some(world) => {
+                if !errors.is_empty() {
+                    match self.config.get_tick_error_policy() {
+                        TickErrorPolicy::Abort => return Err(DorpErr::Entities("Entity Tick", errors)),
+                        TickErrorPolicy::Log => (),
+                        TickErrorPolicy::Remove => {
+                            for &(id, _) in errors.iter() {
+                                world.queue_remove_entity(id);
+                                removed.push(id);
+                            }
+                        },
+                    }
+                    tick_errors = errors;
+                }
                 let mut keys = vec!();
                 for key in world.get_entities().keys() {
                     keys.push(key.clone());
                 }
                 for key in keys {
+                    if removed.contains(&key) {
+                        continue;
+                    }
+                    // an earlier entity's tick_mut may have despawned this one
                     let mut entity = match world.get_mut_entities().remove(&key) {
                         Some(entity) => entity,
-                        None => return Err(DorpErr::Base("World Get Mut Entities Remove was None")),
+                        None => continue,
                     };
                     match entity.tick_mut(self.tick_count, manager, world, match Arc::get_mut(&mut self.sync_data) {
                         Some(matrix_data) => matrix_data,
@@ -253,10 +758,274 @@ impl<T: Entity<T>> Game<T> {
                     }
                     world.add_entity(entity);
                 }
+                let sync_data = match Arc::get_mut(&mut self.sync_data) {
+                    Some(sync_data) => sync_data,
+                    None => return Err(DorpErr::Base("Arc Get Mut Self Sync Data was none")),
+                };
+                match self.scenes.tick_mut(manager, world, sync_data) {
+                    Ok(()) => (),
+                    Err(err) => return Err(DorpErr::Dorp("Self Scenes Tick Mut", Box::new(err))),
+                }
+                if let Some(ref mut hot_reload) = self.hot_reload {
+                    hot_reload.tick_mut(manager, world, sync_data, &mut self.scenes);
+                }
+                world.tick_mut();
+                let tick_mut_end = precise_time_s();
+                world.get_mut_stats().set_tick_durations(parallel_tick_end - parallel_tick_start, tick_mut_end - parallel_tick_end);
+                world.get_mut_stats().set_slowest_entities(durations);
+                world.update_transforms();
+                world.clear_tick_input();
             },
             None => return Err(DorpErr::Base("Arc Get Mut Self World was none")),
         }
         self.tick_count += 1;
-        Ok(())
+        match self.apply_pause_request() {
+            Ok(()) => (),
+            Err(err) => return Err(DorpErr::Dorp("Self Apply Pause Request", Box::new(err))),
+        }
+        Ok(tick_errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::{temp_dir};
+    use std::fs::{File, remove_file};
+    use std::io::{Write};
+    use std::sync::{Arc};
+
+    use input::{InputRecording, KeyCode, ButtonState};
+    use logic::{Game, GameConfig, Id, IdManager, TickErrorPolicy, TestEntity, build_test_entity};
+    use components::{Scene, EntityRegistry, HotReload};
+    use math::{Vec2};
+    use err::DorpErr;
+
+    fn counts(game: &Game<TestEntity>, id: Id) -> (usize, usize, usize) {
+        game.get_world().get_entity_by_id(id).unwrap().get_counts()
+    }
+
+    fn counter_game(manager: &mut IdManager) -> (Game<TestEntity>, Id) {
+        counter_game_with_config(manager, GameConfig::new())
+    }
+
+    // The counter resumes the game on its second paused tick
+    fn counter_game_with_config(manager: &mut IdManager, config: GameConfig) -> (Game<TestEntity>, Id) {
+        let mut game: Game<TestEntity> = Game::new(1, Vec2::from([640.0, 480.0]), config);
+        let id = Id::new(manager);
+        let mut counter = TestEntity::new(id);
+        counter.resume_after = Some(2);
+        game.get_mut_world().unwrap().add_entity(counter);
+        (game, id)
+    }
+
+    fn add_failing_counter(game: &mut Game<TestEntity>, manager: &mut IdManager) -> Id {
+        let id = Id::new(manager);
+        let mut counter = TestEntity::new(id);
+        counter.fails = true;
+        game.get_mut_world().unwrap().add_entity(counter);
+        id
+    }
+
+    #[test]
+    fn recording_replays_after_save_and_load() {
+        let mut manager = IdManager::new();
+        let (mut game, _) = counter_game(&mut manager);
+        game.start_recording();
+        game.step(1, &mut manager).unwrap();
+        let tick = game.get_tick_count();
+        game.update_keyboard(tick, KeyCode::Space, ButtonState::Pressed).unwrap();
+        game.step(2, &mut manager).unwrap();
+        let tick = game.get_tick_count();
+        game.update_keyboard(tick, KeyCode::Space, ButtonState::Released).unwrap();
+        game.update_mouse_pos((12, 34)).unwrap();
+        game.step(1, &mut manager).unwrap();
+        let recording = game.stop_recording().unwrap();
+        let path = temp_dir().join("dorp_game_replay.txt");
+        recording.save(&path).unwrap();
+        let loaded = InputRecording::load(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert_eq!(loaded.get_length(), 4);
+        assert_eq!(loaded.get_events(), recording.get_events());
+
+        let mut replay_manager = IdManager::new();
+        let (mut replayed, _) = counter_game(&mut replay_manager);
+        replayed.replay(&loaded, &mut replay_manager).unwrap();
+        let world = replayed.get_world();
+        assert_eq!(replayed.get_tick_count(), 4);
+        assert!(!world.is_key_pressed(KeyCode::Space));
+        assert!(world.was_key_just_released(KeyCode::Space, 3));
+        assert_eq!(world.get_mouse_position(), Vec2::from([12.0, 34.0]));
+    }
+
+    #[test]
+    fn paused_world_does_not_advance() {
+        let mut manager = IdManager::new();
+        let (mut game, id) = counter_game(&mut manager);
+        game.step(2, &mut manager).unwrap();
+        assert_eq!(counts(&game, id), (2, 2, 0));
+        game.get_mut_world().unwrap().set_paused(true);
+        game.step(1, &mut manager).unwrap();
+        assert_eq!(game.get_tick_count(), 2);
+        assert_eq!(counts(&game, id), (2, 2, 1));
+        game.step(1, &mut manager).unwrap();
+        assert!(!game.get_world().is_paused());
+        game.step(1, &mut manager).unwrap();
+        assert_eq!(game.get_tick_count(), 3);
+        assert_eq!(counts(&game, id), (3, 3, 2));
+    }
+
+    #[test]
+    fn losing_focus_pauses_through_the_pause_request() {
+        let mut manager = IdManager::new();
+        let (mut game, id) = counter_game_with_config(&mut manager, GameConfig::new().with_tick_while_unfocused(false));
+        game.step(1, &mut manager).unwrap();
+        game.update_focus(false).unwrap();
+        assert!(game.get_world().is_paused());
+        game.step(1, &mut manager).unwrap();
+        assert_eq!(game.get_tick_count(), 1);
+        assert_eq!(counts(&game, id), (1, 1, 1));
+        game.update_focus(true).unwrap();
+        assert!(!game.get_world().is_paused());
+        game.step(1, &mut manager).unwrap();
+        assert_eq!(game.get_tick_count(), 2);
+        assert_eq!(counts(&game, id), (2, 2, 1));
+    }
+
+    #[test]
+    fn trailing_events_survive_stop_recording() {
+        let mut manager = IdManager::new();
+        let (mut game, _) = counter_game(&mut manager);
+        game.start_recording();
+        let tick = game.get_tick_count();
+        game.update_keyboard(tick, KeyCode::Space, ButtonState::Pressed).unwrap();
+        game.step(2, &mut manager).unwrap();
+        let tick = game.get_tick_count();
+        game.update_keyboard(tick, KeyCode::Space, ButtonState::Released).unwrap();
+        let recording = game.stop_recording().unwrap();
+        assert_eq!(recording.get_length(), 3);
+
+        let mut replay_manager = IdManager::new();
+        let (mut replayed, _) = counter_game(&mut replay_manager);
+        replayed.replay(&recording, &mut replay_manager).unwrap();
+        assert!(!replayed.get_world().is_key_pressed(KeyCode::Space));
+    }
+
+    #[test]
+    fn recording_across_a_pause_replays_the_same_ticks() {
+        let config = GameConfig::new().with_tick_while_unfocused(false);
+        let mut manager = IdManager::new();
+        let (mut game, id) = counter_game_with_config(&mut manager, config.clone());
+        game.start_recording();
+        game.step(1, &mut manager).unwrap();
+        game.update_focus(false).unwrap();
+        game.step(1, &mut manager).unwrap();
+        let tick = game.get_tick_count();
+        game.update_keyboard(tick, KeyCode::Space, ButtonState::Pressed).unwrap();
+        game.step(2, &mut manager).unwrap();
+        game.update_focus(true).unwrap();
+        let tick = game.get_tick_count();
+        game.update_keyboard(tick, KeyCode::Space, ButtonState::Released).unwrap();
+        game.step(1, &mut manager).unwrap();
+        let recording = game.stop_recording().unwrap();
+        assert_eq!(recording.get_length(), 5);
+        assert_eq!(game.get_tick_count(), 3);
+        assert_eq!(counts(&game, id), (3, 3, 2));
+
+        let mut replay_manager = IdManager::new();
+        let (mut replayed, replayed_id) = counter_game_with_config(&mut replay_manager, config);
+        replayed.replay(&recording, &mut replay_manager).unwrap();
+        let world = replayed.get_world();
+        assert_eq!(replayed.get_tick_count(), 3);
+        assert_eq!(counts(&replayed, replayed_id), (3, 3, 2));
+        assert!(!world.is_paused());
+        assert!(!world.is_key_pressed(KeyCode::Space));
+        assert!(world.was_key_just_released(KeyCode::Space, 2));
+    }
+
+    #[test]
+    fn step_returns_tick_errors_under_log() {
+        let mut manager = IdManager::new();
+        let (mut game, id) = counter_game_with_config(&mut manager, GameConfig::new().with_tick_error_policy(TickErrorPolicy::Log));
+        let failing = add_failing_counter(&mut game, &mut manager);
+        let errors = game.step(2, &mut manager).unwrap();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|&(error_id, _)| error_id == failing));
+        assert!(game.take_tick_errors().is_empty());
+        assert_eq!(counts(&game, id), (2, 2, 0));
+        assert_eq!(counts(&game, failing), (2, 2, 0));
+    }
+
+    #[test]
+    fn step_returns_tick_errors_under_remove() {
+        let mut manager = IdManager::new();
+        let (mut game, id) = counter_game_with_config(&mut manager, GameConfig::new().with_tick_error_policy(TickErrorPolicy::Remove));
+        let failing = add_failing_counter(&mut game, &mut manager);
+        let errors = game.step(2, &mut manager).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, failing);
+        assert!(game.get_world().get_entity_by_id(failing).is_none());
+        assert_eq!(counts(&game, id), (2, 2, 0));
+    }
+
+    #[test]
+    fn step_aborts_with_every_tick_error() {
+        let mut manager = IdManager::new();
+        let (mut game, _) = counter_game(&mut manager);
+        add_failing_counter(&mut game, &mut manager);
+        match game.step(1, &mut manager) {
+            Err(DorpErr::Dorp(_, err)) => match *err {
+                DorpErr::Entities(_, errors) => assert_eq!(errors.len(), 1),
+                _ => panic!("expected entity errors"),
+            },
+            _ => panic!("expected the tick to abort"),
+        }
+    }
+
+    #[test]
+    fn entities_despawned_during_tick_mut_are_skipped() {
+        let mut manager = IdManager::new();
+        let (mut game, id) = counter_game(&mut manager);
+        let first = Id::new(&mut manager);
+        let second = Id::new(&mut manager);
+        {
+            let world = game.get_mut_world().unwrap();
+            let mut first_counter = TestEntity::new(first);
+            first_counter.despawns = vec!(second, id);
+            let mut second_counter = TestEntity::new(second);
+            second_counter.despawns = vec!(first, id);
+            world.add_entity(first_counter);
+            world.add_entity(second_counter);
+        }
+        game.step(1, &mut manager).unwrap();
+        let world = game.get_world();
+        assert_eq!(world.get_entities().len(), 1);
+        assert!(!world.is_entity_alive(id));
+        game.step(1, &mut manager).unwrap();
+    }
+
+    #[test]
+    fn step_drives_scenes_and_hot_reload() {
+        let path = temp_dir().join("dorp_game_hot_reload_scene.yaml");
+        let _ = remove_file(&path);
+        File::create(&path).unwrap().write_all(b"entities:\n  - type: counter\n").unwrap();
+        let mut registry = EntityRegistry::new();
+        registry.register("counter", Box::new(build_test_entity));
+        let mut manager = IdManager::new();
+        let (mut game, _) = counter_game(&mut manager);
+        game.get_mut_scenes().push(Scene::from_file(&path, Arc::new(registry)).unwrap());
+        game.step(1, &mut manager).unwrap();
+        assert_eq!(game.get_scenes().get_depth(), 1);
+        assert_eq!(game.get_world().get_entities().len(), 2);
+
+        remove_file(&path).unwrap();
+        let mut hot_reload = HotReload::new(0.0);
+        hot_reload.watch_scene(&path);
+        game.set_hot_reload(Some(hot_reload));
+        File::create(&path).unwrap().write_all(b"entities:\n  - type: counter\n  - type: counter\n").unwrap();
+        game.step(1, &mut manager).unwrap();
+        remove_file(&path).unwrap();
+        assert!(game.get_mut_hot_reload().unwrap().take_errors().is_empty());
+        assert_eq!(game.get_scenes().get_top_entities().map(|entities| entities.len()), Some(2));
+        assert_eq!(game.get_world().get_entities().len(), 3);
     }
 }
@@ -0,0 +1,126 @@
+use err::DorpErr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TickErrorPolicy {
+    Abort,
+    Log,
+    Remove,
+}
+
+#[derive(Debug, Clone)]
+pub struct GameConfig {
+    tick_rate: f64,
+    max_ticks_per_frame: u32,
+    frame_rate_cap: Option<f64>,
+    vsync: bool,
+    tick_error_policy: TickErrorPolicy,
+    tick_while_unfocused: bool,
+}
+
+impl GameConfig {
+    pub fn new() -> GameConfig {
+        GameConfig {
+            tick_rate: 60.0,
+            max_ticks_per_frame: 10,
+            frame_rate_cap: None,
+            vsync: true,
+            tick_error_policy: TickErrorPolicy::Abort,
+            tick_while_unfocused: true,
+        }
+    }
+
+    pub fn with_tick_rate(mut self, tick_rate: f64) -> GameConfig {
+        self.tick_rate = tick_rate;
+        self
+    }
+
+    pub fn with_max_ticks_per_frame(mut self, max_ticks_per_frame: u32) -> GameConfig {
+        self.max_ticks_per_frame = max_ticks_per_frame;
+        self
+    }
+
+    pub fn with_frame_rate_cap(mut self, frame_rate_cap: Option<f64>) -> GameConfig {
+        self.frame_rate_cap = frame_rate_cap;
+        self
+    }
+
+    // Only windows built with WindowBuilder::with_config pick this up, Game never changes it.
+    pub fn with_vsync(mut self, vsync: bool) -> GameConfig {
+        self.vsync = vsync;
+        self
+    }
+
+    pub fn with_tick_error_policy(mut self, tick_error_policy: TickErrorPolicy) -> GameConfig {
+        self.tick_error_policy = tick_error_policy;
+        self
+    }
+
+    pub fn with_tick_while_unfocused(mut self, tick_while_unfocused: bool) -> GameConfig {
+        self.tick_while_unfocused = tick_while_unfocused;
+        self
+    }
+
+    pub fn get_tick_rate(&self) -> f64 {
+        self.tick_rate
+    }
+
+    pub fn get_tick_delta(&self) -> f64 {
+        1.0 / self.tick_rate
+    }
+
+    pub fn get_max_ticks_per_frame(&self) -> u32 {
+        self.max_ticks_per_frame
+    }
+
+    pub fn get_frame_rate_cap(&self) -> Option<f64> {
+        self.frame_rate_cap
+    }
+
+    pub fn get_vsync(&self) -> bool {
+        self.vsync
+    }
+
+    pub fn get_tick_error_policy(&self) -> TickErrorPolicy {
+        self.tick_error_policy
+    }
+
+    pub fn get_tick_while_unfocused(&self) -> bool {
+        self.tick_while_unfocused
+    }
+
+    pub fn validate(&self) -> Result<(), DorpErr> {
+        if !(self.tick_rate.is_finite() && self.tick_rate > 0.0) {
+            return Err(DorpErr::BaseString(format!("Tick rate must be positive and finite, was {}", self.tick_rate)));
+        }
+        if self.max_ticks_per_frame == 0 {
+            return Err(DorpErr::Base("Max ticks per frame must be at least 1"));
+        }
+        if let Some(frame_rate_cap) = self.frame_rate_cap {
+            if !(frame_rate_cap.is_finite() && frame_rate_cap > 0.0) {
+                return Err(DorpErr::BaseString(format!("Frame rate cap must be positive and finite, was {}", frame_rate_cap)));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use logic::{GameConfig};
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(GameConfig::new().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_bad_rates() {
+        assert!(GameConfig::new().with_tick_rate(0.0).validate().is_err());
+        assert!(GameConfig::new().with_tick_rate(-30.0).validate().is_err());
+        assert!(GameConfig::new().with_tick_rate(::std::f64::NAN).validate().is_err());
+        assert!(GameConfig::new().with_max_ticks_per_frame(0).validate().is_err());
+        assert!(GameConfig::new().with_frame_rate_cap(Some(0.0)).validate().is_err());
+        assert!(GameConfig::new().with_frame_rate_cap(Some(::std::f64::INFINITY)).validate().is_err());
+        assert!(GameConfig::new().with_frame_rate_cap(Some(144.0)).validate().is_ok());
+    }
+}
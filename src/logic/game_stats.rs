@@ -0,0 +1,86 @@
+use std::cmp::{Ordering};
+
+use logic::{Id};
+
+const SLOWEST_ENTITIES_COUNT: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct GameStats {
+    fps: f64,
+    tps: f64,
+    tick_duration: f64,
+    parallel_tick_duration: f64,
+    tick_mut_duration: f64,
+    render_duration: f64,
+    frame_end_duration: f64,
+    slowest_entities: Vec<(Id, f64)>,
+}
+
+impl GameStats {
+    pub fn new() -> GameStats {
+        GameStats {
+            fps: 0.0,
+            tps: 0.0,
+            tick_duration: 0.0,
+            parallel_tick_duration: 0.0,
+            tick_mut_duration: 0.0,
+            render_duration: 0.0,
+            frame_end_duration: 0.0,
+            slowest_entities: vec!(),
+        }
+    }
+
+    pub fn set_rates(&mut self, fps: f64, tps: f64) {
+        self.fps = fps;
+        self.tps = tps;
+    }
+
+    pub fn set_tick_durations(&mut self, parallel_tick_duration: f64, tick_mut_duration: f64) {
+        self.parallel_tick_duration = parallel_tick_duration;
+        self.tick_mut_duration = tick_mut_duration;
+        self.tick_duration = parallel_tick_duration + tick_mut_duration;
+    }
+
+    pub fn set_render_durations(&mut self, render_duration: f64, frame_end_duration: f64) {
+        self.render_duration = render_duration;
+        self.frame_end_duration = frame_end_duration;
+    }
+
+    pub fn set_slowest_entities(&mut self, mut entities: Vec<(Id, f64)>) {
+        entities.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+        entities.truncate(SLOWEST_ENTITIES_COUNT);
+        self.slowest_entities = entities;
+    }
+
+    pub fn get_fps(&self) -> f64 {
+        self.fps
+    }
+
+    pub fn get_tps(&self) -> f64 {
+        self.tps
+    }
+
+    pub fn get_tick_duration(&self) -> f64 {
+        self.tick_duration
+    }
+
+    pub fn get_parallel_tick_duration(&self) -> f64 {
+        self.parallel_tick_duration
+    }
+
+    pub fn get_tick_mut_duration(&self) -> f64 {
+        self.tick_mut_duration
+    }
+
+    pub fn get_render_duration(&self) -> f64 {
+        self.render_duration
+    }
+
+    pub fn get_frame_end_duration(&self) -> f64 {
+        self.frame_end_duration
+    }
+
+    pub fn get_slowest_entities(&self) -> &Vec<(Id, f64)> {
+        &self.slowest_entities
+    }
+}
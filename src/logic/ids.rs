@@ -1,31 +1,123 @@
 use std::collections::{HashMap};
-use std::fmt::{Display, Formatter, Error};
+use std::cmp::{Ordering};
+use std::fmt::{Debug, Display, Formatter, Error};
+use std::hash::{Hash, Hasher};
+use std::marker::{PhantomData};
+use yaml_rust::{Yaml};
+use yaml_rust::yaml::{Hash as YamlHash};
 
-#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, Ord, PartialOrd)]
-pub struct Id {
-    id: IdSize,
+use err::DorpErr;
+
+pub struct Id<K: IdTypeTrait = id_types::Entity> {
+    index: IdSize,
+    generation: IdSize,
+    kind: PhantomData<K>,
 }
 
-impl Id {
+impl<K: IdTypeTrait> Id<K> {
+
+    pub fn new(manager: &mut IdManager) -> Id<K> {
+        manager.next_id()
+    }
 
-    pub fn new(manager: &mut IdManager, id_type: IdType) -> Id {
+    pub fn from_parts(index: IdSize, generation: IdSize) -> Id<K> {
         Id {
-            id: manager.get_id(id_type),
+            index: index,
+            generation: generation,
+            kind: PhantomData,
         }
     }
+
+    pub fn get_index(&self) -> IdSize {
+        self.index
+    }
+
+    pub fn get_generation(&self) -> IdSize {
+        self.generation
+    }
+}
+
+impl<K: IdTypeTrait> Copy for Id<K> {}
+
+impl<K: IdTypeTrait> Clone for Id<K> {
+    fn clone(&self) -> Id<K> {
+        *self
+    }
 }
 
-impl Display for Id {
+impl<K: IdTypeTrait> PartialEq for Id<K> {
+    fn eq(&self, other: &Id<K>) -> bool {
+        self.index == other.index && self.generation == other.generation
+    }
+}
+
+impl<K: IdTypeTrait> Eq for Id<K> {}
+
+impl<K: IdTypeTrait> PartialOrd for Id<K> {
+    fn partial_cmp(&self, other: &Id<K>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: IdTypeTrait> Ord for Id<K> {
+    fn cmp(&self, other: &Id<K>) -> Ordering {
+        (self.index, self.generation).cmp(&(other.index, other.generation))
+    }
+}
+
+impl<K: IdTypeTrait> Hash for Id<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+impl<K: IdTypeTrait> Debug for Id<K> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "Id({:?}, {}, {})", K::get_id_type(), self.index, self.generation)
+    }
+}
+
+impl<K: IdTypeTrait> Display for Id<K> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
-        write!(f, "{}", self.id)
+        write!(f, "{}:{}", self.index, self.generation)
     }
 }
 
 pub trait IdTypeTrait {
+    fn get_id_type() -> IdType;
+}
+
+pub mod id_types {
+    use logic::ids::{IdType, IdTypeTrait};
 
+    macro_rules! id_types {
+        ($($name: ident),*) => {
+            $(
+                pub enum $name {}
+
+                impl IdTypeTrait for $name {
+                    fn get_id_type() -> IdType {
+                        IdType::$name
+                    }
+                }
+            )*
+        }
+    }
+
+    id_types!(World, Entity, Component, Vertex, Index, Texture, DrawMethod, Matrix, Color);
 }
 
-#[derive(Clone, Eq, Hash, PartialEq)]
+pub type EntityId = Id<id_types::Entity>;
+pub type ComponentId = Id<id_types::Component>;
+pub type VertexId = Id<id_types::Vertex>;
+pub type IndexId = Id<id_types::Index>;
+pub type TextureId = Id<id_types::Texture>;
+pub type DrawMethodId = Id<id_types::DrawMethod>;
+pub type MatrixId = Id<id_types::Matrix>;
+pub type ColorId = Id<id_types::Color>;
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum IdType {
     Custom(Box<IdType>),
     World,
@@ -39,8 +131,129 @@ pub enum IdType {
     Color,
 }
 
+impl IdType {
+    pub fn to_string(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    pub fn from_str(name: &str) -> Option<IdType> {
+        if name.starts_with("Custom(") && name.ends_with(")") {
+            return match IdType::from_str(&name[7..name.len() - 1]) {
+                Some(inner) => Some(IdType::Custom(Box::new(inner))),
+                None => None,
+            };
+        }
+        match name {
+            "World" => Some(IdType::World),
+            "Entity" => Some(IdType::Entity),
+            "Component" => Some(IdType::Component),
+            "Vertex" => Some(IdType::Vertex),
+            "Index" => Some(IdType::Index),
+            "Texture" => Some(IdType::Texture),
+            "DrawMethod" => Some(IdType::DrawMethod),
+            "Matrix" => Some(IdType::Matrix),
+            "Color" => Some(IdType::Color),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct IdPool {
+    generations: Vec<IdSize>,
+    alive: Vec<bool>,
+    free: Vec<IdSize>,
+}
+
+impl IdPool {
+    fn new() -> IdPool {
+        IdPool {
+            generations: vec!(),
+            alive: vec!(),
+            free: vec!(),
+        }
+    }
+
+    fn get_id(&mut self) -> (IdSize, IdSize) {
+        match self.free.pop() {
+            Some(index) => {
+                self.alive[index as usize] = true;
+                (index, self.generations[index as usize])
+            },
+            None => {
+                self.generations.push(0);
+                self.alive.push(true);
+                (self.generations.len() as IdSize - 1, 0)
+            },
+        }
+    }
+
+    fn free_id(&mut self, index: IdSize, generation: IdSize) -> bool {
+        if !self.is_alive(index, generation) {
+            return false;
+        }
+        self.generations[index as usize] += 1;
+        self.alive[index as usize] = false;
+        self.free.push(index);
+        true
+    }
+
+    fn is_alive(&self, index: IdSize, generation: IdSize) -> bool {
+        match self.generations.get(index as usize) {
+            Some(current) => *current == generation && self.alive[index as usize],
+            None => false,
+        }
+    }
+
+    fn to_yaml(&self) -> Yaml {
+        let mut hash = YamlHash::new();
+        hash.insert(Yaml::String("generations".to_string()), Yaml::Array(self.generations.iter().map(|generation| Yaml::Integer(*generation as i64)).collect()));
+        hash.insert(Yaml::String("free".to_string()), Yaml::Array(self.free.iter().map(|index| Yaml::Integer(*index as i64)).collect()));
+        Yaml::Hash(hash)
+    }
+
+    fn from_yaml(yaml: &Yaml) -> Result<IdPool, DorpErr> {
+        let generations = match integers_from_yaml(&yaml["generations"]) {
+            Ok(generations) => generations,
+            Err(err) => return Err(DorpErr::Dorp("Integers From Yaml generations", Box::new(err))),
+        };
+        let free = match integers_from_yaml(&yaml["free"]) {
+            Ok(free) => free,
+            Err(err) => return Err(DorpErr::Dorp("Integers From Yaml free", Box::new(err))),
+        };
+        let mut alive = vec!(true; generations.len());
+        for index in free.iter() {
+            match alive.get_mut(*index as usize) {
+                Some(alive) if *alive => *alive = false,
+                Some(_) => return Err(DorpErr::Base("Id Pool free index was repeated")),
+                None => return Err(DorpErr::Base("Id Pool free index was out of range")),
+            }
+        }
+        Ok(IdPool {
+            generations: generations,
+            alive: alive,
+            free: free,
+        })
+    }
+}
+
+fn integers_from_yaml(yaml: &Yaml) -> Result<Vec<IdSize>, DorpErr> {
+    let mut integers = vec!();
+    match yaml.as_vec() {
+        Some(values) => for value in values.iter() {
+            match value.as_i64() {
+                Some(integer) if integer >= 0 => integers.push(integer as IdSize),
+                _ => return Err(DorpErr::Base("Yaml value was not a non-negative integer")),
+            }
+        },
+        None => return Err(DorpErr::Base("Yaml was not an array")),
+    }
+    Ok(integers)
+}
+
+#[derive(Clone)]
 pub struct IdManager {
-    map: HashMap<IdType, IdSize>,
+    map: HashMap<IdType, IdPool>,
 }
 
 impl IdManager {
@@ -51,15 +264,132 @@ impl IdManager {
         }
     }
 
+    pub fn next_id<K: IdTypeTrait>(&mut self) -> Id<K> {
+        let (index, generation) = self.map.entry(K::get_id_type()).or_insert(IdPool::new()).get_id();
+        Id::from_parts(index, generation)
+    }
 
-    fn get_id(&mut self, id_type: IdType) -> IdSize {
-        let id = match self.map.get(&id_type) {
-            Some(id) => *id,
-            None => 0,
+    pub fn free_id<K: IdTypeTrait>(&mut self, id: Id<K>) -> bool {
+        match self.map.get_mut(&K::get_id_type()) {
+            Some(pool) => pool.free_id(id.index, id.generation),
+            None => false,
+        }
+    }
+
+    pub fn is_alive<K: IdTypeTrait>(&self, id: Id<K>) -> bool {
+        match self.map.get(&K::get_id_type()) {
+            Some(pool) => pool.is_alive(id.index, id.generation),
+            None => false,
+        }
+    }
+
+    pub fn to_yaml(&self) -> Yaml {
+        let mut hash = YamlHash::new();
+        for (id_type, pool) in self.map.iter() {
+            hash.insert(Yaml::String(id_type.to_string()), pool.to_yaml());
+        }
+        Yaml::Hash(hash)
+    }
+
+    pub fn from_yaml(yaml: &Yaml) -> Result<IdManager, DorpErr> {
+        let mut manager = IdManager::new();
+        let hash = match yaml.as_hash() {
+            Some(hash) => hash,
+            None => return Err(DorpErr::Base("Id Manager Yaml was not a hash")),
         };
-        self.map.insert(id_type, id + 1);
-        id
+        for (id_type, pool) in hash.iter() {
+            let id_type = match id_type.as_str() {
+                Some(name) => match IdType::from_str(name) {
+                    Some(id_type) => id_type,
+                    None => return Err(DorpErr::BaseString("Unknown Id Type: ".to_string() + name)),
+                },
+                None => return Err(DorpErr::Base("Id Type was not a string")),
+            };
+            match IdPool::from_yaml(pool) {
+                Ok(pool) => {
+                    manager.map.insert(id_type, pool);
+                },
+                Err(err) => return Err(DorpErr::Dorp("Id Pool From Yaml", Box::new(err))),
+            }
+        }
+        Ok(manager)
     }
 }
 
 pub type IdSize = u64;
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust::{YamlLoader, YamlEmitter};
+
+    use logic::{Id, IdManager, EntityId, TextureId};
+
+    #[test]
+    fn free_bumps_generation_and_reuses_index() {
+        let mut manager = IdManager::new();
+        let first: EntityId = Id::new(&mut manager);
+        let second: EntityId = Id::new(&mut manager);
+        assert_eq!((first.get_index(), first.get_generation()), (0, 0));
+        assert_eq!((second.get_index(), second.get_generation()), (1, 0));
+        assert!(manager.free_id(first));
+        let reused: EntityId = Id::new(&mut manager);
+        assert_eq!((reused.get_index(), reused.get_generation()), (0, 1));
+        assert!(manager.is_alive(reused));
+        assert!(manager.is_alive(second));
+    }
+
+    #[test]
+    fn stale_ids_are_rejected() {
+        let mut manager = IdManager::new();
+        let id: EntityId = Id::new(&mut manager);
+        assert!(manager.free_id(id));
+        assert!(!manager.is_alive(id));
+        assert!(!manager.free_id(id));
+        let freed_slot: EntityId = Id::from_parts(0, 1);
+        assert!(!manager.is_alive(freed_slot));
+        assert!(!manager.free_id(freed_slot));
+        let reused: EntityId = Id::new(&mut manager);
+        assert_eq!(reused, freed_slot);
+        assert!(!manager.is_alive(id));
+        assert!(!manager.is_alive(EntityId::from_parts(5, 0)));
+    }
+
+    #[test]
+    fn id_types_have_separate_pools() {
+        let mut manager = IdManager::new();
+        let entity: EntityId = Id::new(&mut manager);
+        let texture: TextureId = Id::new(&mut manager);
+        assert_eq!(entity.get_index(), 0);
+        assert_eq!(texture.get_index(), 0);
+        assert!(manager.free_id(entity));
+        assert!(manager.is_alive(texture));
+    }
+
+    #[test]
+    fn yaml_round_trip_keeps_pools() {
+        let mut manager = IdManager::new();
+        let kept: EntityId = Id::new(&mut manager);
+        let freed: EntityId = Id::new(&mut manager);
+        let texture: TextureId = Id::new(&mut manager);
+        manager.free_id(freed);
+        let mut text = String::new();
+        YamlEmitter::new(&mut text).dump(&manager.to_yaml()).unwrap();
+        let docs = YamlLoader::load_from_str(&text).unwrap();
+        let mut loaded = IdManager::from_yaml(&docs[0]).unwrap();
+        assert!(loaded.is_alive(kept));
+        assert!(loaded.is_alive(texture));
+        assert!(!loaded.is_alive(freed));
+        let reused: EntityId = Id::new(&mut loaded);
+        assert_eq!((reused.get_index(), reused.get_generation()), (1, 1));
+        let next: EntityId = Id::new(&mut loaded);
+        assert_eq!(next.get_index(), 2);
+    }
+
+    #[test]
+    fn from_yaml_rejects_bad_free_lists() {
+        let docs = YamlLoader::load_from_str("Entity:\n  generations: [0, 1]\n  free: [1, 1]\n").unwrap();
+        assert!(IdManager::from_yaml(&docs[0]).is_err());
+        let docs = YamlLoader::load_from_str("Entity:\n  generations: [0]\n  free: [3]\n").unwrap();
+        assert!(IdManager::from_yaml(&docs[0]).is_err());
+    }
+}
@@ -1,13 +1,27 @@
 mod game;
+mod game_config;
+mod game_stats;
 mod world;
 mod ids;
 pub mod entity;
 mod opterr;
 mod tick_count;
+mod quit_request;
+mod snapshot;
+mod file_watcher;
+#[cfg(test)]
+mod fixtures;
 
 pub use self::game::{Game};
+pub use self::game_config::{GameConfig, TickErrorPolicy};
+pub use self::game_stats::{GameStats};
 pub use self::world::{World};
-pub use self::ids::{Id, IdType, IdManager};
+pub use self::ids::{Id, IdType, IdTypeTrait, IdManager, IdSize, id_types, EntityId, ComponentId, VertexId, IndexId, TextureId, DrawMethodId, MatrixId, ColorId};
 pub use self::entity::{Entity};
 pub use self::opterr::{OptErr};
 pub use self::tick_count::{TickCount};
+pub use self::quit_request::{QuitRequest};
+pub use self::snapshot::{Persistent, SNAPSHOT_VERSION, save_snapshot, load_snapshot, snapshot_to_yaml, snapshot_from_yaml, id_to_yaml, id_from_yaml};
+pub use self::file_watcher::{FileWatcher};
+#[cfg(test)]
+pub use self::fixtures::{TestEntity, test_world, build_test_entity};
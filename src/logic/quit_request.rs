@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuitRequest {
+    code: i32,
+    reason: String,
+}
+
+impl QuitRequest {
+    pub fn new(code: i32, reason: String) -> QuitRequest {
+        QuitRequest {
+            code: code,
+            reason: reason,
+        }
+    }
+
+    pub fn get_code(&self) -> i32 {
+        self.code
+    }
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+}
@@ -0,0 +1,303 @@
+use std::fs::{File};
+use std::io::{Read, Write};
+use std::path::{Path};
+use yaml_rust::{Yaml, YamlLoader, YamlEmitter};
+use yaml_rust::yaml::{Hash as YamlHash};
+
+use logic::{World, Entity, Id, IdManager, IdTypeTrait, IdSize};
+use err::DorpErr;
+
+pub const SNAPSHOT_VERSION: i64 = 1;
+
+// For components, entities persist themselves through Entity::persist and Entity::restore
+pub trait Persistent: Sized {
+    fn save(&self) -> Yaml;
+    fn load(&Yaml, &mut IdManager) -> Result<Self, DorpErr>;
+}
+
+pub fn id_to_yaml<K: IdTypeTrait>(id: Id<K>) -> Yaml {
+    Yaml::Array(vec!(Yaml::Integer(id.get_index() as i64), Yaml::Integer(id.get_generation() as i64)))
+}
+
+pub fn id_from_yaml<K: IdTypeTrait>(yaml: &Yaml) -> Result<Id<K>, DorpErr> {
+    match yaml.as_vec() {
+        Some(parts) if parts.len() == 2 => match (parts[0].as_i64(), parts[1].as_i64()) {
+            (Some(index), Some(generation)) if index >= 0 && generation >= 0 => Ok(Id::from_parts(index as IdSize, generation as IdSize)),
+            _ => Err(DorpErr::Base("Id Yaml parts were not non-negative integers")),
+        },
+        _ => Err(DorpErr::Base("Id Yaml was not an array of two integers")),
+    }
+}
+
+pub fn snapshot_to_yaml<T: Entity<T>>(world: &World<T>, manager: &IdManager) -> Yaml {
+    let mut ids_manager = manager.clone();
+    for id in world.get_dead_ids().iter() {
+        ids_manager.free_id(*id);
+    }
+    let mut ids: Vec<&Id> = world.get_entities().keys().collect();
+    ids.sort();
+    let mut entities = vec!();
+    let mut saved_ids = vec!();
+    for id in ids {
+        if let Some(entity) = world.get_entity_by_id(*id) {
+            match entity.persist() {
+                Some(entity) => {
+                    entities.push(entity);
+                    saved_ids.push(*id);
+                },
+                None => ids_manager.free_id(*id),
+            }
+        }
+    }
+    let mut names = YamlHash::new();
+    for (name, id) in world.get_names().iter() {
+        if saved_ids.contains(id) {
+            names.insert(Yaml::String(name.clone()), id_to_yaml(*id));
+        }
+    }
+    let mut hash = YamlHash::new();
+    hash.insert(Yaml::String("version".to_string()), Yaml::Integer(SNAPSHOT_VERSION));
+    hash.insert(Yaml::String("ids".to_string()), ids_manager.to_yaml());
+    hash.insert(Yaml::String("names".to_string()), Yaml::Hash(names));
+    hash.insert(Yaml::String("entities".to_string()), Yaml::Array(entities));
+    Yaml::Hash(hash)
+}
+
+pub fn snapshot_from_yaml<T: Entity<T>>(yaml: &Yaml, world: &mut World<T>, manager: &mut IdManager) -> Result<(), DorpErr> {
+    match yaml["version"].as_i64() {
+        Some(version) if version < 1 => return Err(DorpErr::Base("Snapshot version must be at least 1")),
+        Some(version) if version <= SNAPSHOT_VERSION => (),
+        Some(_) => return Err(DorpErr::Base("Snapshot version is newer than supported")),
+        None => return Err(DorpErr::Base("Snapshot version was missing")),
+    }
+    let mut restored = match IdManager::from_yaml(&yaml["ids"]) {
+        Ok(restored) => restored,
+        Err(err) => return Err(DorpErr::Dorp("Id Manager From Yaml", Box::new(err))),
+    };
+    let mut entities = vec!();
+    if let Some(entity_yamls) = yaml["entities"].as_vec() {
+        for entity in entity_yamls.iter() {
+            match T::restore(entity, &mut restored) {
+                Ok(entity) => entities.push(entity),
+                Err(err) => return Err(DorpErr::Dorp("Entity Restore", Box::new(err))),
+            }
+        }
+    }
+    let mut names = vec!();
+    if let Some(name_yamls) = yaml["names"].as_hash() {
+        for (name, id) in name_yamls.iter() {
+            let name = match name.as_str() {
+                Some(name) => name,
+                None => return Err(DorpErr::Base("Snapshot name was not a string")),
+            };
+            match id_from_yaml(id) {
+                Ok(id) => if entities.iter().any(|entity| entity.get_id() == id) {
+                    names.push((name.to_string(), id));
+                },
+                Err(err) => return Err(DorpErr::DorpString("Id From Yaml for name: ".to_string() + name, Box::new(err))),
+            }
+        }
+    }
+    for entity in entities.iter() {
+        if let Some(named) = entity.get_named() {
+            let id = entity.get_id();
+            let existing = names.iter().find(|&&(ref name, _)| name == named.get_name()).map(|&(_, other)| other);
+            match existing {
+                Some(other) if other == id => (),
+                Some(_) => return Err(DorpErr::BaseString("Snapshot name belongs to another entity: ".to_string() + named.get_name())),
+                None => names.push((named.get_name().to_string(), id)),
+            }
+        }
+    }
+    *manager = restored;
+    let active_camera = world.get_active_camera();
+    world.clear_entities();
+    world.clear_names();
+    for entity in entities {
+        world.add_entity(entity);
+    }
+    for (name, id) in names {
+        world.restore_name(name, id);
+    }
+    if let Some(id) = active_camera {
+        if world.get_entity_by_id(id).map_or(false, |entity| entity.get_camera().is_some()) {
+            world.set_active_camera(Some(id));
+        }
+    }
+    Ok(())
+}
+
+pub fn save_snapshot<T: Entity<T>, P: AsRef<Path>>(path: P, world: &World<T>, manager: &IdManager) -> Result<(), DorpErr> {
+    let mut text = String::new();
+    {
+        let mut emitter = YamlEmitter::new(&mut text);
+        match emitter.dump(&snapshot_to_yaml(world, manager)) {
+            Ok(()) => (),
+            Err(err) => return Err(DorpErr::YamlEmit("Yaml Emitter Dump", err)),
+        }
+    }
+    let mut file = match File::create(path) {
+        Ok(file) => file,
+        Err(err) => return Err(DorpErr::Io("File Create", err)),
+    };
+    match file.write_all(text.as_bytes()) {
+        Ok(()) => Ok(()),
+        Err(err) => Err(DorpErr::Io("File Write All", err)),
+    }
+}
+
+pub fn load_snapshot<T: Entity<T>, P: AsRef<Path>>(path: P, world: &mut World<T>, manager: &mut IdManager) -> Result<(), DorpErr> {
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) => return Err(DorpErr::Io("File Open", err)),
+    };
+    let mut text = String::new();
+    match file.read_to_string(&mut text) {
+        Ok(_) => (),
+        Err(err) => return Err(DorpErr::Io("File Read To String", err)),
+    }
+    let docs = match YamlLoader::load_from_str(&text) {
+        Ok(docs) => docs,
+        Err(err) => return Err(DorpErr::YamlScan("Yaml Loader Load From Str", err)),
+    };
+    match docs.get(0) {
+        Some(doc) => match snapshot_from_yaml(doc, world, manager) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(DorpErr::Dorp("Snapshot From Yaml", Box::new(err))),
+        },
+        None => Err(DorpErr::Base("Snapshot file was empty")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust::{Yaml, YamlLoader, YamlEmitter};
+    use yaml_rust::yaml::{Hash as YamlHash};
+
+    use logic::{Entity, Id, IdManager, TestEntity, test_world, snapshot_to_yaml, snapshot_from_yaml, id_to_yaml};
+    use components::{Named, Camera};
+    use math::{Vec2};
+
+    fn reparse(yaml: &Yaml) -> Yaml {
+        let mut text = String::new();
+        YamlEmitter::new(&mut text).dump(yaml).unwrap();
+        YamlLoader::load_from_str(&text).unwrap().remove(0)
+    }
+
+    #[test]
+    fn named_entities_survive_save_and_load() {
+        let mut manager = IdManager::new();
+        let mut saved = test_world();
+        let player = Id::new(&mut manager);
+        let named = Named::new("player", player, &mut saved).unwrap();
+        saved.add_entity(TestEntity::new(player).with_named(named));
+        let rock = Id::new(&mut manager);
+        saved.add_entity(TestEntity::new(rock));
+        let yaml = reparse(&snapshot_to_yaml(&saved, &manager));
+
+        let mut loaded_manager = IdManager::new();
+        let mut loaded = test_world();
+        snapshot_from_yaml(&yaml, &mut loaded, &mut loaded_manager).unwrap();
+        match loaded.get_entity_by_name("player") {
+            Some(entity) => {
+                assert_eq!(entity.get_id(), player);
+                assert_eq!(entity.get_named().map(|named| named.get_name()), Some("player"));
+            },
+            None => panic!("Named entity was not restored"),
+        }
+        assert!(loaded.is_entity_alive(rock));
+        assert!(loaded_manager.is_alive(player));
+        assert!(Named::new("player", rock, &mut loaded).is_err());
+    }
+
+    #[test]
+    fn names_missing_from_the_table_are_registered() {
+        let mut manager = IdManager::new();
+        let mut saved = test_world();
+        let player = Id::new(&mut manager);
+        saved.add_entity(TestEntity::new(player).with_named(Named::unregistered("player")));
+        let yaml = reparse(&snapshot_to_yaml(&saved, &manager));
+
+        let mut loaded = test_world();
+        snapshot_from_yaml(&yaml, &mut loaded, &mut IdManager::new()).unwrap();
+        assert_eq!(loaded.get_names().get("player"), Some(&player));
+    }
+
+    #[test]
+    fn saving_frees_dead_ids_without_touching_the_world() {
+        let mut manager = IdManager::new();
+        let mut saved = test_world();
+        let rock = Id::new(&mut manager);
+        saved.add_entity(TestEntity::new(rock));
+        saved.queue_remove_entity(rock);
+        saved.tick_mut();
+        let yaml = reparse(&snapshot_to_yaml(&saved, &manager));
+        assert_eq!(saved.get_dead_ids(), &vec!(rock));
+        assert!(manager.is_alive(rock));
+
+        let mut loaded_manager = IdManager::new();
+        snapshot_from_yaml(&yaml, &mut test_world(), &mut loaded_manager).unwrap();
+        assert!(!loaded_manager.is_alive(rock));
+    }
+
+    #[test]
+    fn versions_below_one_are_rejected() {
+        let mut yaml = snapshot_to_yaml(&test_world(), &IdManager::new());
+        if let Yaml::Hash(ref mut hash) = yaml {
+            hash.insert(Yaml::String("version".to_string()), Yaml::Integer(0));
+        }
+        assert!(snapshot_from_yaml(&yaml, &mut test_world(), &mut IdManager::new()).is_err());
+    }
+
+    #[test]
+    fn unpersisted_entities_and_their_names_are_left_out() {
+        let mut manager = IdManager::new();
+        let mut saved = test_world();
+        let player = Id::new(&mut manager);
+        let named = Named::new("player", player, &mut saved).unwrap();
+        saved.add_entity(TestEntity::new(player).with_named(named));
+        let effect = Id::new(&mut manager);
+        let named = Named::new("effect", effect, &mut saved).unwrap();
+        let mut effect_entity = TestEntity::new(effect).with_named(named);
+        effect_entity.persisted = false;
+        saved.add_entity(effect_entity);
+        let mut yaml = reparse(&snapshot_to_yaml(&saved, &manager));
+        assert_eq!(yaml["entities"].as_vec().map(|entities| entities.len()), Some(1));
+        assert_eq!(yaml["names"]["effect"], Yaml::BadValue);
+        if let Yaml::Hash(ref mut hash) = yaml {
+            let mut names = YamlHash::new();
+            names.insert(Yaml::String("player".to_string()), id_to_yaml(player));
+            names.insert(Yaml::String("stale".to_string()), id_to_yaml(effect));
+            hash.insert(Yaml::String("names".to_string()), Yaml::Hash(names));
+        }
+
+        let mut loaded_manager = IdManager::new();
+        let mut loaded = test_world();
+        snapshot_from_yaml(&yaml, &mut loaded, &mut loaded_manager).unwrap();
+        assert!(!loaded.is_entity_alive(effect));
+        assert!(!loaded_manager.is_alive(effect));
+        assert_eq!(loaded.get_names().get("player"), Some(&player));
+        assert!(loaded.get_names().get("stale").is_none());
+    }
+
+    #[test]
+    fn active_camera_is_kept_only_if_it_was_restored() {
+        let mut manager = IdManager::new();
+        let mut saved = test_world();
+        let eye = Id::new(&mut manager);
+        saved.add_entity(TestEntity::new(eye).with_camera(Camera::new_screen(Vec2::from([640.0, 480.0]))));
+        let yaml = reparse(&snapshot_to_yaml(&saved, &manager));
+
+        let mut loaded = test_world();
+        loaded.set_active_camera(Some(eye));
+        snapshot_from_yaml(&yaml, &mut loaded, &mut IdManager::new()).unwrap();
+        assert_eq!(loaded.get_active_camera(), Some(eye));
+
+        let mut other_manager = IdManager::new();
+        let other = Id::new(&mut other_manager);
+        let mut loaded = test_world();
+        loaded.set_active_camera(Some(other));
+        snapshot_from_yaml(&reparse(&snapshot_to_yaml(&test_world(), &other_manager)), &mut loaded, &mut IdManager::new()).unwrap();
+        assert_eq!(loaded.get_active_camera(), None);
+    }
+}
@@ -1,8 +1,9 @@
 use std::collections::{HashMap};
+use std::path::{PathBuf};
 
-use input::{Keyboard, Mouse, Display, KeyCode, MouseButton, Button};
-use logic::{Id, Entity};
-use math::{Vec2};
+use input::{Keyboard, Mouse, Display, KeyCode, MouseButton, Button, InputMap, TickInput, TouchPoint};
+use logic::{Id, Entity, GameStats, QuitRequest, TickCount};
+use math::{Vec2, Vec3, Mat4, Ray};
 use err::DorpErr;
 
 #[derive(Debug)]
@@ -10,9 +11,18 @@ pub struct World<T: Entity<T>> {
     keyboard: Keyboard,
     mouse: Mouse,
     display: Display,
+    input_map: InputMap,
+    tick_input: TickInput,
     entities: HashMap<Id, T>,
-    names: HashMap<&'static str, Id>,
+    names: HashMap<String, Id>,
     to_remove: Vec<Id>,
+    dead_ids: Vec<Id>,
+    stats: GameStats,
+    active_camera: Option<Id>,
+    paused: bool,
+    focused: bool,
+    pause_request: Option<bool>,
+    quit_request: Option<QuitRequest>,
 }
 
 impl<T: Entity<T>> World<T> {
@@ -21,9 +31,18 @@ impl<T: Entity<T>> World<T> {
             keyboard: keyboard,
             mouse: mouse,
             display: display,
+            input_map: InputMap::new(),
+            tick_input: TickInput::new(),
             entities: HashMap::new(),
             names: HashMap::new(),
             to_remove: vec!(),
+            dead_ids: vec!(),
+            stats: GameStats::new(),
+            active_camera: None,
+            paused: false,
+            focused: true,
+            pause_request: None,
+            quit_request: None,
         }
     }
 
@@ -39,8 +58,58 @@ impl<T: Entity<T>> World<T> {
         self.mouse.set_mouse_position(pos);
     }
 
+    pub fn push_character(&mut self, character: char) {
+        self.tick_input.push_character(character);
+    }
+
+    pub fn add_scroll_lines(&mut self, delta: Vec2) {
+        self.tick_input.add_scroll_lines(delta);
+    }
+
+    pub fn add_scroll_pixels(&mut self, delta: Vec2) {
+        self.tick_input.add_scroll_pixels(delta);
+    }
+
+    pub fn push_dropped_file(&mut self, path: PathBuf) {
+        self.tick_input.push_dropped_file(path);
+    }
+
+    pub fn push_touch(&mut self, touch: TouchPoint) {
+        self.tick_input.push_touch(touch);
+    }
+
+    pub fn clear_tick_input(&mut self) {
+        self.tick_input.clear();
+        self.mouse.clear_delta();
+    }
+
+    pub fn get_text_input(&self) -> &str {
+        self.tick_input.get_text()
+    }
+
+    pub fn get_scroll_lines(&self) -> Vec2 {
+        self.tick_input.get_scroll_lines()
+    }
+
+    pub fn get_scroll_pixels(&self) -> Vec2 {
+        self.tick_input.get_scroll_pixels()
+    }
+
+    pub fn get_dropped_files(&self) -> &Vec<PathBuf> {
+        self.tick_input.get_dropped_files()
+    }
+
+    pub fn get_touches(&self) -> &Vec<TouchPoint> {
+        self.tick_input.get_touches()
+    }
+
     pub fn set_resolution(&mut self, resolution:  Vec2) {
         self.display.set_resolution(resolution);
+        for (_, entity) in self.entities.iter_mut() {
+            if let Some(camera) = entity.get_mut_camera() {
+                camera.set_resolution(resolution);
+            }
+        }
     }
 
     pub fn get_key(&self, key_code: KeyCode) -> Button {
@@ -51,10 +120,82 @@ impl<T: Entity<T>> World<T> {
         self.mouse.get_button(mouse_button)
     }
 
+    pub fn is_key_pressed(&self, key_code: KeyCode) -> bool {
+        self.keyboard.get_key(key_code).is_pressed()
+    }
+
+    pub fn was_key_just_pressed(&self, key_code: KeyCode, tick: TickCount) -> bool {
+        self.keyboard.get_key(key_code).was_just_pressed(tick)
+    }
+
+    pub fn was_key_just_released(&self, key_code: KeyCode, tick: TickCount) -> bool {
+        self.keyboard.get_key(key_code).was_just_released(tick)
+    }
+
+    pub fn get_key_held_ticks(&self, key_code: KeyCode, tick: TickCount) -> TickCount {
+        self.keyboard.get_key(key_code).get_held_ticks(tick)
+    }
+
+    pub fn is_mouse_button_pressed(&self, mouse_button: MouseButton) -> bool {
+        self.mouse.get_button(mouse_button).is_pressed()
+    }
+
+    pub fn was_mouse_button_just_pressed(&self, mouse_button: MouseButton, tick: TickCount) -> bool {
+        self.mouse.get_button(mouse_button).was_just_pressed(tick)
+    }
+
+    pub fn was_mouse_button_just_released(&self, mouse_button: MouseButton, tick: TickCount) -> bool {
+        self.mouse.get_button(mouse_button).was_just_released(tick)
+    }
+
+    pub fn get_mouse_button_held_ticks(&self, mouse_button: MouseButton, tick: TickCount) -> TickCount {
+        self.mouse.get_button(mouse_button).get_held_ticks(tick)
+    }
+
+    pub fn set_input_map(&mut self, input_map: InputMap) {
+        self.input_map = input_map;
+    }
+
+    pub fn get_input_map(&self) -> &InputMap {
+        &self.input_map
+    }
+
+    pub fn get_mut_input_map(&mut self) -> &mut InputMap {
+        &mut self.input_map
+    }
+
+    pub fn is_action_pressed(&self, name: &str) -> bool {
+        self.input_map.is_action_pressed(name, &self.keyboard, &self.mouse)
+    }
+
+    pub fn was_action_just_pressed(&self, name: &str, tick: TickCount) -> bool {
+        self.input_map.was_action_just_pressed(name, tick, &self.keyboard, &self.mouse)
+    }
+
+    pub fn was_action_just_released(&self, name: &str, tick: TickCount) -> bool {
+        self.input_map.was_action_just_released(name, tick, &self.keyboard, &self.mouse)
+    }
+
+    pub fn get_axis(&self, name: &str) -> f32 {
+        self.input_map.get_axis_value(name, &self.keyboard, &self.mouse)
+    }
+
     pub fn get_mouse_position(&self) -> Vec2 {
         self.mouse.get_mouse_position()
     }
 
+    pub fn get_mouse_delta(&self) -> Vec2 {
+        self.mouse.get_mouse_delta()
+    }
+
+    pub fn get_mouse_normalized_position(&self) -> Vec2 {
+        self.mouse.get_normalized_position(self.display.get_resolution())
+    }
+
+    pub fn get_mouse_ray(&self, perspective: Mat4, view: Mat4) -> Ray {
+        Ray::from_screen(self.get_mouse_normalized_position(), perspective, view)
+    }
+
     pub fn get_resolution(&self) -> Vec2 {
         self.display.get_resolution()
     }
@@ -63,6 +204,56 @@ impl<T: Entity<T>> World<T> {
         self.display.get_aspect_ratio()
     }
 
+    pub fn get_screen_projection(&self) -> Mat4 {
+        self.display.get_screen_projection()
+    }
+
+    pub fn get_stats(&self) -> &GameStats {
+        &self.stats
+    }
+
+    pub fn get_mut_stats(&mut self) -> &mut GameStats {
+        &mut self.stats
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    pub fn request_pause(&mut self) {
+        self.pause_request = Some(true);
+    }
+
+    pub fn request_resume(&mut self) {
+        self.pause_request = Some(false);
+    }
+
+    pub fn take_pause_request(&mut self) -> Option<bool> {
+        self.pause_request.take()
+    }
+
+    pub fn request_quit(&mut self, code: i32, reason: String) {
+        if self.quit_request.is_none() {
+            self.quit_request = Some(QuitRequest::new(code, reason));
+        }
+    }
+
+    pub fn get_quit_request(&self) -> Option<&QuitRequest> {
+        self.quit_request.as_ref()
+    }
+
     pub fn get_entities(&self) -> &HashMap<Id, T> {
         &self.entities
     }
@@ -71,21 +262,213 @@ impl<T: Entity<T>> World<T> {
         &mut self.entities
     }
 
-    pub fn add_entity(&mut self, entity: T) {
+    pub fn add_entity(&mut self, mut entity: T) {
+        let resolution = self.display.get_resolution();
+        if let Some(camera) = entity.get_mut_camera() {
+            if camera.get_resolution() != resolution {
+                camera.set_resolution(resolution);
+            }
+        }
         self.entities.insert(entity.get_id(), entity);
     }
 
+    pub fn for_each_entity_mut<F>(&mut self, mut f: F) -> Result<(), DorpErr> where F: FnMut(&mut T, &mut World<T>) -> Result<(), DorpErr> {
+        let mut keys = vec!();
+        for key in self.entities.keys() {
+            keys.push(key.clone());
+        }
+        for key in keys {
+            let mut entity = match self.entities.remove(&key) {
+                Some(entity) => entity,
+                None => return Err(DorpErr::Base("Self Entities Remove was None")),
+            };
+            let result = f(&mut entity, self);
+            self.add_entity(entity);
+            match result {
+                Ok(()) => (),
+                Err(err) => return Err(DorpErr::DorpString("Entity ".to_string() + &key.to_string(), Box::new(err))),
+            }
+        }
+        Ok(())
+    }
+
     pub fn tick_mut(&mut self) {
         let len = self.to_remove.len();
         for id in self.to_remove.drain(0..len) {
-            self.entities.remove(&id);
+            if self.entities.remove(&id).is_some() {
+                self.names.retain(|_, name_id| *name_id != id);
+                self.dead_ids.push(id);
+            }
         }
     }
 
+    pub fn get_dead_ids(&self) -> &Vec<Id> {
+        &self.dead_ids
+    }
+
+    pub fn take_dead_ids(&mut self) -> Vec<Id> {
+        let len = self.dead_ids.len();
+        self.dead_ids.drain(0..len).collect()
+    }
+
+    pub fn is_entity_alive(&self, id: Id) -> bool {
+        self.entities.contains_key(&id)
+    }
+
     pub fn queue_remove_entity(&mut self, id: Id) {
         self.to_remove.push(id);
     }
 
+    pub fn pick(&self, ray: Ray) -> Option<(Id, Vec3)> {
+        let mut nearest: Option<(Id, f32)> = None;
+        for (id, entity) in self.entities.iter() {
+            if let Some(renderable) = entity.get_renderable() {
+                let hit = match entity.get_transform() {
+                    Some(transform) => renderable.intersect_with_model(ray, transform.get_world_matrix()),
+                    None => renderable.intersect(ray),
+                };
+                if let Some(distance) = hit {
+                    nearest = match nearest {
+                        Some((other_id, other)) if other <= distance => Some((other_id, other)),
+                        _ => Some((*id, distance)),
+                    };
+                }
+            }
+        }
+        nearest.map(|(id, distance)| (id, ray.get_point(distance)))
+    }
+
+    pub fn get_parent(&self, id: Id) -> Option<Id> {
+        match self.entities.get(&id).and_then(|entity| entity.get_transform()) {
+            Some(transform) => transform.get_parent(),
+            None => None,
+        }
+    }
+
+    pub fn get_children(&self, id: Id) -> Vec<Id> {
+        let mut children = vec!();
+        for (child, entity) in self.entities.iter() {
+            if let Some(transform) = entity.get_transform() {
+                if transform.get_parent() == Some(id) {
+                    children.push(*child);
+                }
+            }
+        }
+        children
+    }
+
+    pub fn get_world_matrix(&self, id: Id) -> Option<Mat4> {
+        self.entities.get(&id).and_then(|entity| entity.get_transform()).map(|transform| transform.get_world_matrix())
+    }
+
+    pub fn get_world_position(&self, id: Id) -> Option<Vec3> {
+        self.entities.get(&id).and_then(|entity| entity.get_transform()).map(|transform| transform.get_world_position())
+    }
+
+    pub fn set_parent(&mut self, child: Id, parent: Option<Id>) -> Result<(), DorpErr> {
+        let parent_matrix = match parent {
+            Some(parent) => {
+                let mut ancestor = Some(parent);
+                while let Some(current) = ancestor {
+                    if current == child {
+                        return Err(DorpErr::Base("Can't parent an entity to itself or one of its descendants"));
+                    }
+                    ancestor = self.get_parent(current);
+                }
+                match self.get_world_matrix(parent) {
+                    Some(parent_matrix) => parent_matrix,
+                    None => return Err(DorpErr::Base("Parent entity has no transform")),
+                }
+            },
+            None => Mat4::identity(),
+        };
+        match self.entities.get_mut(&child).and_then(|entity| entity.get_mut_transform()) {
+            Some(transform) => {
+                transform.set_parent(parent, parent_matrix);
+                Ok(())
+            },
+            None => Err(DorpErr::Base("Child entity has no mutable transform")),
+        }
+    }
+
+    fn parents_first(&self) -> Vec<Id> {
+        let mut depths: Vec<(usize, Id)> = vec!();
+        for id in self.entities.keys() {
+            let mut depth = 0;
+            let mut ancestor = self.get_parent(*id);
+            while let Some(current) = ancestor {
+                depth += 1;
+                ancestor = self.get_parent(current);
+            }
+            depths.push((depth, *id));
+        }
+        depths.sort();
+        depths.into_iter().map(|(_, id)| id).collect()
+    }
+
+    pub fn update_transforms(&mut self) {
+        let mut world_matrices: HashMap<Id, Mat4> = HashMap::new();
+        for id in self.parents_first() {
+            let parent_matrix = match self.get_parent(id) {
+                Some(parent) => world_matrices.get(&parent).cloned(),
+                None => None,
+            };
+            let mut world_matrix = None;
+            if let Some(transform) = self.entities.get_mut(&id).and_then(|entity| entity.get_mut_transform()) {
+                transform.update_world(parent_matrix);
+                world_matrix = Some(transform.get_world_matrix());
+            }
+            if world_matrix.is_none() {
+                world_matrix = self.get_world_matrix(id);
+            }
+            if let Some(world_matrix) = world_matrix {
+                world_matrices.insert(id, world_matrix);
+            }
+        }
+    }
+
+    pub fn update_render_transforms(&mut self, alpha: f64) {
+        let mut world_matrices: HashMap<Id, Mat4> = HashMap::new();
+        for id in self.parents_first() {
+            let parent_matrix = match self.get_parent(id) {
+                Some(parent) => world_matrices.get(&parent).cloned(),
+                None => None,
+            };
+            let mut world_matrix = None;
+            if let Some(transform) = self.entities.get_mut(&id).and_then(|entity| entity.get_mut_transform()) {
+                if let Some(parent_matrix) = parent_matrix {
+                    transform.set_render_parent_matrix(parent_matrix);
+                }
+                world_matrix = Some(transform.get_interpolated_world_matrix(alpha));
+            }
+            if world_matrix.is_none() {
+                world_matrix = self.get_world_matrix(id);
+            }
+            if let Some(world_matrix) = world_matrix {
+                world_matrices.insert(id, world_matrix);
+            }
+        }
+    }
+
+    pub fn set_active_camera(&mut self, id: Option<Id>) {
+        self.active_camera = id;
+    }
+
+    pub fn get_active_camera(&self) -> Option<Id> {
+        self.active_camera
+    }
+
+    pub fn get_camera_matrices(&self, alpha: f64) -> Option<(Mat4, Mat4)> {
+        let entity = match self.active_camera.and_then(|id| self.entities.get(&id)) {
+            Some(entity) => entity,
+            None => return None,
+        };
+        match entity.get_camera() {
+            Some(camera) => Some((camera.get_projection_matrix(), camera.get_interpolated_view_matrix(entity.get_transform().map(|transform| transform.as_ref()), alpha))),
+            None => None,
+        }
+    }
+
     pub fn get_entity_by_id(&self, id: Id) -> Option<&T> {
         self.entities.get(&id)
     }
@@ -98,14 +481,23 @@ impl<T: Entity<T>> World<T> {
         self.entities.remove(&id)
     }
 
-    pub fn get_entity_by_name(&self, name: &'static str) -> Option<&T> {
+    pub fn despawn_entity(&mut self, id: Id) -> Option<T> {
+        let entity = self.entities.remove(&id);
+        if entity.is_some() {
+            self.names.retain(|_, name_id| *name_id != id);
+            self.dead_ids.push(id);
+        }
+        entity
+    }
+
+    pub fn get_entity_by_name(&self, name: &str) -> Option<&T> {
         if let Some(id) = self.names.get(name) {
             return self.get_entity_by_id(*id);
         }
         return None;
     }
 
-    pub fn get_mut_entity_by_name(&mut self, name: &'static str) -> Option<&mut T> {
+    pub fn get_mut_entity_by_name(&mut self, name: &str) -> Option<&mut T> {
         let id = match self.names.get(name) {
             Some(id) => id.clone(),
             None => return None,
@@ -113,19 +505,162 @@ impl<T: Entity<T>> World<T> {
         self.get_mut_entity_by_id(id)
     }
 
-    pub fn register_name(&mut self, id: Id, name: &'static str) -> Result<(), DorpErr> {
+    pub fn register_name(&mut self, id: Id, name: &str) -> Result<(), DorpErr> {
         if !self.names.contains_key(name) {
-            self.names.insert(name, id);
+            self.names.insert(name.to_string(), id);
             Ok(())
         } else {
             Err(DorpErr::BaseString("Names already contains name: ".to_string() + name))
         }
     }
 
-    pub fn deregister_name(&mut self, name: &'static str) -> Result<(), DorpErr> {
+    pub fn get_names(&self) -> &HashMap<String, Id> {
+        &self.names
+    }
+
+    pub fn restore_name(&mut self, name: String, id: Id) {
+        self.names.insert(name, id);
+    }
+
+    pub fn clear_names(&mut self) {
+        self.names.clear();
+    }
+
+    pub fn clear_entities(&mut self) {
+        self.entities.clear();
+        self.active_camera = None;
+        self.to_remove.clear();
+        self.dead_ids.clear();
+    }
+
+    pub fn deregister_name(&mut self, name: &str) -> Result<(), DorpErr> {
         if self.names.remove(name).is_none() {
             return Err(DorpErr::BaseString("Can't deregister name '".to_string() + name + "' because name is not registered"));
         }
         return Ok(());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use logic::{Entity, TestEntity, IdManager, test_world};
+    use components::{Camera};
+    use math::{Vec2, Vec3, Ray};
+
+    #[test]
+    fn pick_uses_transform_without_rendering() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let moved = TestEntity::quad(&mut manager, Vec3::from([10.0, 0.0, 0.0]));
+        let moved_id = moved.get_id();
+        world.add_entity(moved);
+        world.add_entity(TestEntity::quad(&mut manager, Vec3::zero()));
+        let ray = Ray::new(Vec3::from([10.0, 0.0, 5.0]), Vec3::from([0.0, 0.0, -1.0]));
+        match world.pick(ray) {
+            Some((id, point)) => {
+                assert_eq!(id, moved_id);
+                assert_eq!(point, Vec3::from([10.0, 0.0, 0.0]));
+            },
+            None => panic!("Pick missed the moved quad"),
+        }
+    }
+
+    #[test]
+    fn taken_entities_keep_their_ids() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let quad = TestEntity::quad(&mut manager, Vec3::zero());
+        let id = quad.get_id();
+        world.add_entity(quad);
+        let quad = world.take_entity_by_id(id).unwrap();
+        assert!(!world.is_entity_alive(id));
+        assert!(world.take_dead_ids().is_empty());
+        world.add_entity(quad);
+        assert!(world.is_entity_alive(id));
+    }
+
+    #[test]
+    fn despawned_entities_release_their_ids() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let quad = TestEntity::quad(&mut manager, Vec3::zero());
+        let id = quad.get_id();
+        world.add_entity(quad);
+        assert!(world.despawn_entity(id).is_some());
+        assert!(world.despawn_entity(id).is_none());
+        assert!(!world.is_entity_alive(id));
+        assert_eq!(world.take_dead_ids(), vec!(id));
+    }
+
+    #[test]
+    fn removed_entities_drop_their_names() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let despawned = TestEntity::quad(&mut manager, Vec3::zero());
+        let despawned_id = despawned.get_id();
+        let queued = TestEntity::quad(&mut manager, Vec3::zero());
+        let queued_id = queued.get_id();
+        world.add_entity(despawned);
+        world.add_entity(queued);
+        world.register_name(despawned_id, "despawned").unwrap();
+        world.register_name(despawned_id, "despawned_alias").unwrap();
+        world.register_name(queued_id, "queued").unwrap();
+        world.despawn_entity(despawned_id).unwrap();
+        assert!(world.get_names().get("despawned").is_none());
+        assert!(world.get_names().get("despawned_alias").is_none());
+        assert_eq!(world.get_names().get("queued"), Some(&queued_id));
+        world.queue_remove_entity(queued_id);
+        world.tick_mut();
+        assert!(world.get_names().is_empty());
+        world.register_name(queued_id, "despawned").unwrap();
+    }
+
+    #[test]
+    fn children_follow_interpolated_parents() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let parent = TestEntity::quad(&mut manager, Vec3::zero());
+        let parent_id = parent.get_id();
+        let child = TestEntity::quad(&mut manager, Vec3::from([1.0, 0.0, 0.0]));
+        let child_id = child.get_id();
+        world.add_entity(parent);
+        world.add_entity(child);
+        world.set_parent(child_id, Some(parent_id)).unwrap();
+        world.update_transforms();
+        {
+            let transform = world.get_mut_entity_by_id(parent_id).and_then(|entity| entity.get_mut_transform()).unwrap();
+            transform.tick_mut();
+            transform.set_position(Vec3::from([10.0, 0.0, 0.0]));
+        }
+        world.update_transforms();
+        world.update_render_transforms(0.5);
+        let model = world.get_entity_by_id(child_id).and_then(|entity| entity.get_transform()).unwrap().get_interpolated_world_matrix(0.5);
+        assert_eq!(Vec3::from(model.to_transpose() * Vec3::zero().to_vec4(1.0)), Vec3::from([6.0, 0.0, 0.0]));
+        assert_eq!(world.get_world_position(child_id), Some(Vec3::from([11.0, 0.0, 0.0])));
+    }
+
+    #[test]
+    fn pick_returns_nearest() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        let near = TestEntity::quad(&mut manager, Vec3::from([0.0, 0.0, 2.0]));
+        let near_id = near.get_id();
+        world.add_entity(TestEntity::quad(&mut manager, Vec3::zero()));
+        world.add_entity(near);
+        let ray = Ray::new(Vec3::from([0.0, 0.0, 5.0]), Vec3::from([0.0, 0.0, -1.0]));
+        assert_eq!(world.pick(ray).map(|(id, _)| id), Some(near_id));
+    }
+
+    #[test]
+    fn cameras_added_after_a_resize_use_the_current_resolution() {
+        let mut manager = IdManager::new();
+        let mut world = test_world();
+        world.set_resolution(Vec2::from([800.0, 400.0]));
+        let eye = TestEntity::quad(&mut manager, Vec3::zero()).with_camera(Camera::new_perspective(90.0, 0.1, 100.0, Vec2::from([640.0, 480.0])));
+        let id = eye.get_id();
+        world.add_entity(eye);
+        let camera = world.get_entity_by_id(id).and_then(|entity| entity.get_camera()).unwrap();
+        assert_eq!(camera.get_resolution(), Vec2::from([800.0, 400.0]));
+        assert_eq!(camera.get_aspect_ratio(), 2.0);
+    }
+}
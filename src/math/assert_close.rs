@@ -0,0 +1,21 @@
+use math::{Vec3, Vec4, Mat4};
+
+pub fn assert_mat4_close(actual: Mat4, expected: Mat4) {
+	for row in 0..4 {
+		for column in 0..4 {
+			assert!((actual[row][column] - expected[row][column]).abs() < 0.0001, "\n{}\n!=\n{}", actual, expected);
+		}
+	}
+}
+
+pub fn assert_vec3_close(actual: Vec3, expected: Vec3) {
+	for i in 0..3 {
+		assert!((actual[i] - expected[i]).abs() < 0.0001, "{} != {}", actual, expected);
+	}
+}
+
+pub fn assert_vec4_close(actual: Vec4, expected: Vec4) {
+	for i in 0..4 {
+		assert!((actual[i] - expected[i]).abs() < 0.0001, "{} != {}", actual, expected);
+	}
+}
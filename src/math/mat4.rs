@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter, Error};
 use std::f32::consts::{PI};
 use glium::uniforms::{AsUniformValue, UniformValue};
 
-use math::{Vec3, Vec4, DEG_TO_RAD};
+use math::{Vec2, Vec3, Vec4, DEG_TO_RAD};
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct Mat4 {
@@ -39,6 +39,7 @@ impl Mat4 {
 		)
 	}
 
+	#[deprecated(note = "scales by a field of view like a perspective, use orthographic_bounds")]
 	pub fn orthographic(near: f32, far: f32, field_of_view: f32, aspect_ratio: f32) -> Mat4 {
 		let field_of_view = field_of_view * DEG_TO_RAD;
 		let d = 1.0 / ((field_of_view / 2.0).tan());
@@ -55,6 +56,24 @@ impl Mat4 {
 		)
 	}
 
+	pub fn orthographic_bounds(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Mat4 {
+		Mat4::from([[
+					2.0 / (right - left), 	0.0, 					0.0, 					-(right + left) / (right - left),
+				],[
+					0.0, 					2.0 / (top - bottom), 	0.0, 					-(top + bottom) / (top - bottom),
+				],[
+					0.0, 					0.0, 					-2.0 / (far - near), 	-(far + near) / (far - near),
+				],[
+					0.0, 					0.0, 					0.0, 					1.0,
+				]
+			]
+		)
+	}
+
+	pub fn screen_space(resolution: Vec2, near: f32, far: f32) -> Mat4 {
+		Mat4::orthographic_bounds(0.0, resolution[0], 0.0, resolution[1], near, far)
+	}
+
 	pub fn view_deg(pitch: f32, yaw: f32, camera_position: Vec3) -> Mat4 {
 		Mat4::view(pitch * PI / 180.0, yaw * PI / 180.0, camera_position)
 	}
@@ -206,6 +225,73 @@ impl Mat4 {
 	}
 
 
+	pub fn to_transpose(&self) -> Mat4 {
+		let mut new = Mat4::zero();
+		for y in 0..4 {
+			for x in 0..4 {
+				new[y][x] = self[x][y];
+			}
+		}
+		new
+	}
+
+
+	pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Mat4 {
+		let z = (eye - target).normalize();
+		let x = up.cross(z).normalize();
+		let y = z.cross(x);
+
+		Mat4::from([[
+					x[0], 	x[1], 	x[2], 	-x.dot(eye),
+				],[
+					y[0], 	y[1], 	y[2], 	-y.dot(eye),
+				],[
+					z[0], 	z[1], 	z[2], 	-z.dot(eye),
+				],[
+					0.0, 	0.0, 	0.0, 	1.0,
+				]
+			]
+		)
+	}
+
+
+	pub fn determinant(&self) -> f32 {
+		let m = self;
+		let s0 = m[0][0] * m[1][1] - m[1][0] * m[0][1];
+		let s1 = m[0][0] * m[1][2] - m[1][0] * m[0][2];
+		let s2 = m[0][0] * m[1][3] - m[1][0] * m[0][3];
+		let s3 = m[0][1] * m[1][2] - m[1][1] * m[0][2];
+		let s4 = m[0][1] * m[1][3] - m[1][1] * m[0][3];
+		let s5 = m[0][2] * m[1][3] - m[1][2] * m[0][3];
+
+		let c5 = m[2][2] * m[3][3] - m[3][2] * m[2][3];
+		let c4 = m[2][1] * m[3][3] - m[3][1] * m[2][3];
+		let c3 = m[2][1] * m[3][2] - m[3][1] * m[2][2];
+		let c2 = m[2][0] * m[3][3] - m[3][0] * m[2][3];
+		let c1 = m[2][0] * m[3][2] - m[3][0] * m[2][2];
+		let c0 = m[2][0] * m[3][1] - m[3][0] * m[2][1];
+
+		s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+	}
+
+
+	// Applies self as built by the constructors above (self * v). Products made with `*` come out
+	// transposed, like Transform's local and world matrices, so call to_transpose() on those first.
+	pub fn transform_point(&self, point: Vec3) -> Vec3 {
+		let result = *self * point.to_vec4(1.0);
+		if result[3] != 0.0 && result[3] != 1.0 {
+			Vec3::from(result) / result[3]
+		} else {
+			Vec3::from(result)
+		}
+	}
+
+
+	pub fn transform_vector(&self, vector: Vec3) -> Vec3 {
+		Vec3::from(*self * vector.to_vec4(0.0))
+	}
+
+
 	fn swap_rows(&mut self, y1: usize, y2: usize) {
 		let row = self[y1];
 		self[y1] = self[y2];
@@ -311,3 +397,87 @@ impl Display for Mat4 {
 		write!(f, "{}\n{}\n{}\n{}", self[0], self[1], self[2], self[3])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec2, Vec3, Vec4, Mat4, Quat, assert_mat4_close, assert_vec3_close, assert_vec4_close};
+
+	fn sample() -> Mat4 {
+		Mat4::from([
+			[2.0, 0.0, 1.0, 3.0],
+			[1.0, 3.0, 0.0, -1.0],
+			[0.0, 1.0, 4.0, 2.0],
+			[0.0, 0.0, 0.0, 1.0],
+		])
+	}
+
+	#[test]
+	fn mul_composes_transposed() {
+		let a = Mat4::translation_from_vec3(Vec3::from([1.0, 2.0, 3.0]));
+		let b = sample();
+		let mut expected = Mat4::zero();
+		for row in 0..4 {
+			for column in 0..4 {
+				for i in 0..4 {
+					expected[row][column] += b[row][i] * a[i][column];
+				}
+			}
+		}
+		assert_mat4_close(a * b, expected.to_transpose());
+	}
+
+	#[test]
+	fn inverse_undoes_matrix() {
+		let matrix = sample();
+		assert_mat4_close((matrix.to_inverse() * matrix).to_transpose(), Mat4::identity());
+		assert_mat4_close(matrix.to_inverse().to_inverse(), matrix);
+	}
+
+	#[test]
+	fn determinant_matches_known_values() {
+		assert_eq!(Mat4::identity().determinant(), 1.0);
+		assert_eq!(Mat4::scalation_from_vec3(Vec3::from([2.0, 3.0, 4.0])).determinant(), 24.0);
+		assert!((Mat4::rotation_from_vec3(Vec3::from([0.3, -1.2, 2.1])).determinant() - 1.0).abs() < 0.0001);
+		assert_eq!(sample().determinant(), 25.0);
+	}
+
+	#[test]
+	fn transform_point_uses_constructor_layout() {
+		let translation = Mat4::translation_from_vec3(Vec3::from([4.0, 5.0, 6.0]));
+		assert_vec3_close(translation.transform_point(Vec3::zero()), Vec3::from([4.0, 5.0, 6.0]));
+		assert_vec3_close(translation.transform_vector(Vec3::from([1.0, 0.0, 0.0])), Vec3::from([1.0, 0.0, 0.0]));
+		let local = Mat4::scalation_from_vec3(Vec3::from([2.0, 2.0, 2.0])) * Quat::identity().to_mat4().to_transpose() * translation;
+		assert_vec3_close(local.to_transpose().transform_point(Vec3::from([1.0, 0.0, 0.0])), Vec3::from([6.0, 5.0, 6.0]));
+		assert_vec3_close(local.to_transpose().transform_vector(Vec3::from([1.0, 0.0, 0.0])), Vec3::from([2.0, 0.0, 0.0]));
+	}
+
+	#[test]
+	fn transform_point_divides_by_w() {
+		let perspective = Mat4::perspective(0.1, 100.0, 90.0, 1.0);
+		let point = perspective.transform_point(Vec3::from([1.0, 1.0, -2.0]));
+		assert_vec3_close(Vec3::from([point[0], point[1], 0.0]), Vec3::from([0.5, 0.5, 0.0]));
+	}
+
+	#[test]
+	fn look_at_matches_view() {
+		let eye = Vec3::from([1.0, 2.0, 5.0]);
+		let look_at = Mat4::look_at(eye, eye - Vec3::from([0.0, 0.0, 1.0]), Vec3::from([0.0, 1.0, 0.0]));
+		assert_mat4_close(look_at, Mat4::view(0.0, 0.0, eye));
+		assert_eq!(look_at * Vec4::from([1.0, 2.0, 5.0, 1.0]), Vec4::from([0.0, 0.0, 0.0, 1.0]));
+	}
+
+	#[test]
+	fn orthographic_bounds_maps_box_to_ndc() {
+		let ortho = Mat4::orthographic_bounds(-4.0, 6.0, -2.0, 3.0, 0.5, 20.0);
+		assert_vec4_close(ortho * Vec4::from([-4.0, -2.0, -0.5, 1.0]), Vec4::from([-1.0, -1.0, -1.0, 1.0]));
+		assert_vec4_close(ortho * Vec4::from([6.0, 3.0, -20.0, 1.0]), Vec4::from([1.0, 1.0, 1.0, 1.0]));
+	}
+
+	#[test]
+	fn screen_space_maps_pixels_to_ndc() {
+		let screen = Mat4::screen_space(Vec2::from([640.0, 480.0]), -1.0, 1.0);
+		assert_vec4_close(screen * Vec4::from([0.0, 0.0, 0.0, 1.0]), Vec4::from([-1.0, -1.0, 0.0, 1.0]));
+		assert_vec4_close(screen * Vec4::from([640.0, 480.0, 0.0, 1.0]), Vec4::from([1.0, 1.0, 0.0, 1.0]));
+		assert_vec4_close(screen * Vec4::from([320.0, 240.0, 0.0, 1.0]), Vec4::from([0.0, 0.0, 0.0, 1.0]));
+	}
+}
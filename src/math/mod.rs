@@ -2,11 +2,19 @@ mod vec2;
 mod vec3;
 mod vec4;
 mod mat4;
+mod ray;
+mod quat;
+#[cfg(test)]
+mod assert_close;
 
 pub use self::vec2::Vec2;
 pub use self::vec3::Vec3;
 pub use self::vec4::Vec4;
 pub use self::mat4::Mat4;
+pub use self::ray::Ray;
+pub use self::quat::Quat;
+#[cfg(test)]
+pub use self::assert_close::{assert_mat4_close, assert_vec3_close, assert_vec4_close};
 
 use std::f32::consts::{PI};
 
@@ -0,0 +1,216 @@
+use std::ops::{Index, IndexMut, Mul};
+use std::fmt::{Display, Formatter, Error};
+
+use math::{Vec3, Mat4};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Quat {
+	vals: [f32; 4],
+}
+
+impl Quat {
+
+	pub fn identity() -> Quat {
+		Quat::from([0.0, 0.0, 0.0, 1.0])
+	}
+
+
+	pub fn from_axis_angle(axis: Vec3, rads: f32) -> Quat {
+		let length = axis.length();
+		if length == 0.0 {
+			return Quat::identity();
+		}
+		let half = rads / 2.0;
+		let s = half.sin() / length;
+		Quat::from([axis[0] * s, axis[1] * s, axis[2] * s, half.cos()])
+	}
+
+
+	pub fn from_euler(vec3: Vec3) -> Quat {
+		Quat::from_axis_angle(Vec3::from([1.0, 0.0, 0.0]), vec3[0]) * Quat::from_axis_angle(Vec3::from([0.0, 0.0, 1.0]), -vec3[2]) * Quat::from_axis_angle(Vec3::from([0.0, 1.0, 0.0]), -vec3[1])
+	}
+
+
+	pub fn get_vals(&self) -> [f32; 4] {
+		self.vals
+	}
+
+
+	pub fn dot(&self, other: Quat) -> f32 {
+		let mut sum = 0.0;
+
+		for i in 0..4 {
+			sum += self[i] * other[i];
+		}
+
+		sum
+	}
+
+
+	pub fn length(&self) -> f32 {
+		self.dot(*self).sqrt()
+	}
+
+
+	pub fn normalize(&self) -> Quat {
+		let length = self.length();
+		if length == 0.0 {
+			return Quat::identity();
+		}
+		Quat::from([self[0] / length, self[1] / length, self[2] / length, self[3] / length])
+	}
+
+
+	pub fn conjugate(&self) -> Quat {
+		Quat::from([-self[0], -self[1], -self[2], self[3]])
+	}
+
+
+	pub fn inverse(&self) -> Quat {
+		let length_squared = self.dot(*self);
+		if length_squared == 0.0 {
+			return Quat::identity();
+		}
+		let conjugate = self.conjugate();
+		Quat::from([conjugate[0] / length_squared, conjugate[1] / length_squared, conjugate[2] / length_squared, conjugate[3] / length_squared])
+	}
+
+
+	pub fn slerp(&self, other: Quat, t: f32) -> Quat {
+		let mut other = other;
+		let mut cos = self.dot(other);
+		if cos < 0.0 {
+			other = Quat::from([-other[0], -other[1], -other[2], -other[3]]);
+			cos = -cos;
+		}
+		let (a, b) = if cos > 0.9995 {
+			(1.0 - t, t)
+		} else {
+			let theta = cos.acos();
+			let sin = theta.sin();
+			(((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+		};
+		Quat::from([
+			self[0] * a + other[0] * b,
+			self[1] * a + other[1] * b,
+			self[2] * a + other[2] * b,
+			self[3] * a + other[3] * b,
+		]).normalize()
+	}
+
+
+	pub fn to_mat4(&self) -> Mat4 {
+		let (x, y, z, w) = (self[0], self[1], self[2], self[3]);
+		Mat4::from([[
+					1.0 - 2.0 * (y * y + z * z),	2.0 * (x * y - z * w),			2.0 * (x * z + y * w),			0.0,
+				],[
+					2.0 * (x * y + z * w),			1.0 - 2.0 * (x * x + z * z),	2.0 * (y * z - x * w),			0.0,
+				],[
+					2.0 * (x * z - y * w),			2.0 * (y * z + x * w),			1.0 - 2.0 * (x * x + y * y),	0.0,
+				],[
+					0.0,							0.0,							0.0,							1.0,
+				]
+			]
+		)
+	}
+
+
+	pub fn rotate_vec3(&self, vec3: Vec3) -> Vec3 {
+		let u = Vec3::from([self[0], self[1], self[2]]);
+		let uv = u.cross(vec3);
+		let uuv = u.cross(uv);
+		vec3 + uv * (2.0 * self[3]) + uuv * 2.0
+	}
+}
+
+impl From<[f32; 4]> for Quat {
+
+	fn from(vals: [f32; 4]) -> Quat {
+		Quat {
+			vals: vals,
+		}
+	}
+}
+
+impl Index<usize> for Quat {
+	type Output = f32;
+
+
+	fn index(&self, index: usize) -> &f32 {
+		&self.vals[index]
+	}
+}
+
+impl IndexMut<usize> for Quat {
+
+	fn index_mut(&mut self, index: usize) -> &mut f32 {
+		&mut self.vals[index]
+	}
+}
+
+impl Mul<Quat> for Quat {
+	type Output = Quat;
+
+
+	fn mul(self, other: Quat) -> Quat {
+		let (x1, y1, z1, w1) = (self[0], self[1], self[2], self[3]);
+		let (x2, y2, z2, w2) = (other[0], other[1], other[2], other[3]);
+		Quat::from([
+			w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+			w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+			w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+			w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+		])
+	}
+}
+
+impl Display for Quat {
+	fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
+		write!(f, "({}, {}, {}, {})", self[0], self[1], self[2], self[3])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec3, Mat4, Quat, assert_mat4_close};
+
+	#[test]
+	fn from_euler_matches_rotation_from_vec3() {
+		let eulers = [
+			Vec3::from([0.7, 0.0, 0.0]),
+			Vec3::from([0.0, 0.7, 0.0]),
+			Vec3::from([0.0, 0.0, 0.7]),
+			Vec3::from([0.3, -1.2, 2.1]),
+		];
+		for euler in eulers.iter() {
+			assert_mat4_close(Quat::from_euler(*euler).to_mat4().to_transpose(), Mat4::rotation_from_vec3(*euler));
+		}
+	}
+
+	#[test]
+	fn to_mat4_matches_axis_rotations() {
+		assert_mat4_close(Quat::from_axis_angle(Vec3::from([1.0, 0.0, 0.0]), 0.5).to_mat4(), Mat4::x_rotation(0.5));
+		assert_mat4_close(Quat::from_axis_angle(Vec3::from([0.0, 1.0, 0.0]), 0.5).to_mat4(), Mat4::y_rotation(0.5));
+		assert_mat4_close(Quat::from_axis_angle(Vec3::from([0.0, 0.0, 1.0]), 0.5).to_mat4(), Mat4::z_rotation(0.5));
+	}
+
+	#[test]
+	fn rotate_vec3_matches_to_mat4() {
+		let quat = Quat::from_euler(Vec3::from([0.3, -1.2, 2.1]));
+		let vec3 = Vec3::from([1.0, 2.0, 3.0]);
+		let rotated = quat.rotate_vec3(vec3);
+		let expected = Vec3::from(quat.to_mat4() * vec3.to_vec4(0.0));
+		for i in 0..3 {
+			assert!((rotated[i] - expected[i]).abs() < 0.0001);
+		}
+	}
+
+	#[test]
+	fn slerp_reaches_both_ends() {
+		let from = Quat::identity();
+		let to = Quat::from_axis_angle(Vec3::from([0.0, 1.0, 0.0]), 1.0);
+		assert_eq!(from.slerp(to, 0.0), from);
+		let end = from.slerp(to, 1.0);
+		assert!((end.dot(to) - 1.0).abs() < 0.0001);
+	}
+}
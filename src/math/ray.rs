@@ -0,0 +1,100 @@
+use math::{Vec2, Vec3, Vec4, Mat4};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ray {
+	origin: Vec3,
+	direction: Vec3,
+}
+
+impl Ray {
+	pub fn new(origin: Vec3, direction: Vec3) -> Ray {
+		Ray {
+			origin: origin,
+			direction: direction.normalize(),
+		}
+	}
+
+	pub fn from_screen(normalized: Vec2, perspective: Mat4, view: Mat4) -> Ray {
+		let inverse = (view * perspective).to_transpose().to_inverse();
+		let near = inverse * Vec4::from([normalized[0], normalized[1], -1.0, 1.0]);
+		let far = inverse * Vec4::from([normalized[0], normalized[1], 1.0, 1.0]);
+		let near = Vec3::from(near) * (1.0 / near[3]);
+		let far = Vec3::from(far) * (1.0 / far[3]);
+		Ray::new(near, far - near)
+	}
+
+	pub fn get_origin(&self) -> Vec3 {
+		self.origin
+	}
+
+	pub fn get_direction(&self) -> Vec3 {
+		self.direction
+	}
+
+	pub fn get_point(&self, distance: f32) -> Vec3 {
+		self.origin + self.direction * distance
+	}
+
+	pub fn intersect_triangle(&self, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+		let epsilon = 0.000001;
+		let edge1 = b - a;
+		let edge2 = c - a;
+		let p = self.direction.cross(edge2);
+		let determinant = edge1.dot(p);
+		if determinant.abs() < epsilon {
+			return None;
+		}
+		let inverse_determinant = 1.0 / determinant;
+		let t = self.origin - a;
+		let u = t.dot(p) * inverse_determinant;
+		if u < 0.0 || u > 1.0 {
+			return None;
+		}
+		let q = t.cross(edge1);
+		let v = self.direction.dot(q) * inverse_determinant;
+		if v < 0.0 || u + v > 1.0 {
+			return None;
+		}
+		let distance = edge2.dot(q) * inverse_determinant;
+		if distance > epsilon {
+			Some(distance)
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec2, Vec3, Mat4, Ray, assert_vec3_close};
+
+	#[test]
+	fn from_screen_center_starts_on_near_plane() {
+		let perspective = Mat4::perspective(0.1, 100.0, 90.0, 1.0);
+		let view = Mat4::view(0.0, 0.0, Vec3::from([0.0, 0.0, 5.0]));
+		let ray = Ray::from_screen(Vec2::zero(), perspective, view);
+		assert_vec3_close(ray.get_origin(), Vec3::from([0.0, 0.0, 4.0]));
+		assert_vec3_close(ray.get_direction(), Vec3::from([0.0, 0.0, -1.0]));
+	}
+
+	#[test]
+	fn from_screen_corner_follows_field_of_view() {
+		let perspective = Mat4::perspective(0.1, 100.0, 90.0, 1.0);
+		let view = Mat4::view(0.0, 0.0, Vec3::from([0.0, 0.0, 5.0]));
+		let ray = Ray::from_screen(Vec2::from([1.0, 0.0]), perspective, view);
+		let direction = ray.get_direction();
+		assert!((direction[0] + direction[2]).abs() < 0.0001, "{}", direction);
+		assert!(direction[0] > 0.0);
+	}
+
+	#[test]
+	fn intersect_triangle_hits_in_front() {
+		let ray = Ray::new(Vec3::from([0.0, 0.0, 5.0]), Vec3::from([0.0, 0.0, -1.0]));
+		let a = Vec3::from([-1.0, -1.0, 0.0]);
+		let b = Vec3::from([1.0, -1.0, 0.0]);
+		let c = Vec3::from([0.0, 1.0, 0.0]);
+		assert_eq!(ray.intersect_triangle(a, b, c), Some(5.0));
+		let behind = Ray::new(Vec3::from([0.0, 0.0, 5.0]), Vec3::from([0.0, 0.0, 1.0]));
+		assert_eq!(behind.intersect_triangle(a, b, c), None);
+	}
+}
@@ -1,5 +1,5 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
-use std::ops::{Index, IndexMut, Add, Sub, Mul};
+use std::ops::{Index, IndexMut, Add, Sub, Mul, Div, Neg};
 use std::fmt::{Display, Formatter, Error};
 
 use math::{Vec3, Vec4};
@@ -45,6 +45,50 @@ impl Vec2 {
 	pub fn to_vec4(&self, z: f32, w: f32) -> Vec4 {
 		Vec4::from([self[0], self[1], z, w])
 	}
+
+    
+	pub fn length_squared(&self) -> f32 {
+		self.dot(*self)
+	}
+
+    
+	pub fn length(&self) -> f32 {
+		self.length_squared().sqrt()
+	}
+
+    
+	pub fn normalize(&self) -> Vec2 {
+		let length = self.length();
+		if length == 0.0 {
+			return *self;
+		}
+		*self / length
+	}
+
+    
+	pub fn distance(&self, other: Vec2) -> f32 {
+		(other - *self).length()
+	}
+
+    
+	pub fn lerp(&self, other: Vec2, t: f32) -> Vec2 {
+		*self + (other - *self) * t
+	}
+
+    
+	pub fn min(&self, other: Vec2) -> Vec2 {
+		Vec2::from([self[0].min(other[0]), self[1].min(other[1])])
+	}
+
+    
+	pub fn max(&self, other: Vec2) -> Vec2 {
+		Vec2::from([self[0].max(other[0]), self[1].max(other[1])])
+	}
+
+    
+	pub fn abs(&self) -> Vec2 {
+		Vec2::from([self[0].abs(), self[1].abs()])
+	}
 }
 
 impl From<[f32; 2]> for Vec2 {
@@ -129,8 +173,73 @@ impl Mul<f32> for Vec2 {
 	}
 }
 
+impl Div<Vec2> for Vec2 {
+	type Output = Vec2;
+
+    
+	fn div(self, other: Vec2) -> Vec2 {
+		Vec2::from([self[0] / other[0], self[1] / other[1]])
+	}
+}
+
+impl Div<f32> for Vec2 {
+	type Output = Vec2;
+
+    
+	fn div(self, other: f32) -> Vec2 {
+		Vec2::from([self[0] / other, self[1] / other])
+	}
+}
+
+impl Neg for Vec2 {
+	type Output = Vec2;
+
+    
+	fn neg(self) -> Vec2 {
+		Vec2::from([-self[0], -self[1]])
+	}
+}
+
 impl Display for Vec2 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
         write!(f, "({}, {})", self[0], self[1])
     }
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec2, Vec3};
+
+	#[test]
+	fn length_and_normalize() {
+		let vec2 = Vec2::from([3.0, 4.0]);
+		assert_eq!(vec2.length_squared(), 25.0);
+		assert_eq!(vec2.length(), 5.0);
+		assert_eq!(vec2.normalize(), Vec2::from([0.6, 0.8]));
+		assert_eq!(Vec2::zero().normalize(), Vec2::zero());
+		assert_eq!(vec2.distance(Vec2::zero()), 5.0);
+		assert_eq!(vec2.dot(Vec2::from([-4.0, 3.0])), 0.0);
+	}
+
+	#[test]
+	fn component_helpers() {
+		let a = Vec2::from([1.0, -2.0]);
+		let b = Vec2::from([-3.0, 4.0]);
+		assert_eq!(a.lerp(b, 0.5), Vec2::from([-1.0, 1.0]));
+		assert_eq!(a.lerp(b, 0.0), a);
+		assert_eq!(a.lerp(b, 1.0), b);
+		assert_eq!(a.min(b), Vec2::from([-3.0, -2.0]));
+		assert_eq!(a.max(b), Vec2::from([1.0, 4.0]));
+		assert_eq!(a.abs(), Vec2::from([1.0, 2.0]));
+		assert_eq!(-a, Vec2::from([-1.0, 2.0]));
+		assert_eq!(b / 2.0, Vec2::from([-1.5, 2.0]));
+		assert_eq!(b / a, Vec2::from([-3.0, -2.0]));
+	}
+
+	#[test]
+	fn conversions() {
+		let a = Vec2::from([1.0, 2.0]);
+		assert_eq!(a.to_vec3(3.0), Vec3::from([1.0, 2.0, 3.0]));
+		assert_eq!(Vec2::from(a.to_vec4(3.0, 4.0)), a);
+	}
+}
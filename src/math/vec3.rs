@@ -1,5 +1,5 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
-use std::ops::{Index, IndexMut, Add, Sub, Mul};
+use std::ops::{Index, IndexMut, Add, Sub, Mul, Div, Neg};
 use std::fmt::{Display, Formatter, Error};
 
 use math::{Vec4};
@@ -40,6 +40,59 @@ impl Vec3 {
 	pub fn to_vec4(&self, w: f32) -> Vec4 {
 		Vec4::from([self[0], self[1], self[2], w])
 	}
+
+    
+	pub fn length_squared(&self) -> f32 {
+		self.dot(*self)
+	}
+
+    
+	pub fn length(&self) -> f32 {
+		self.length_squared().sqrt()
+	}
+
+    
+	pub fn normalize(&self) -> Vec3 {
+		let length = self.length();
+		if length == 0.0 {
+			return *self;
+		}
+		*self / length
+	}
+
+    
+	pub fn distance(&self, other: Vec3) -> f32 {
+		(other - *self).length()
+	}
+
+    
+	pub fn lerp(&self, other: Vec3, t: f32) -> Vec3 {
+		*self + (other - *self) * t
+	}
+
+    
+	pub fn min(&self, other: Vec3) -> Vec3 {
+		Vec3::from([self[0].min(other[0]), self[1].min(other[1]), self[2].min(other[2])])
+	}
+
+    
+	pub fn max(&self, other: Vec3) -> Vec3 {
+		Vec3::from([self[0].max(other[0]), self[1].max(other[1]), self[2].max(other[2])])
+	}
+
+    
+	pub fn abs(&self) -> Vec3 {
+		Vec3::from([self[0].abs(), self[1].abs(), self[2].abs()])
+	}
+
+    
+	pub fn cross(&self, other: Vec3) -> Vec3 {
+		Vec3::from([
+			self[1] * other[2] - self[2] * other[1],
+			self[2] * other[0] - self[0] * other[2],
+			self[0] * other[1] - self[1] * other[0],
+		])
+	}
 }
 
 impl From<[f32; 3]> for Vec3 {
@@ -117,8 +170,70 @@ impl Mul<f32> for Vec3 {
 	}
 }
 
+impl Div<Vec3> for Vec3 {
+	type Output = Vec3;
+
+    
+	fn div(self, other: Vec3) -> Vec3 {
+		Vec3::from([self[0] / other[0], self[1] / other[1], self[2] / other[2]])
+	}
+}
+
+impl Div<f32> for Vec3 {
+	type Output = Vec3;
+
+    
+	fn div(self, other: f32) -> Vec3 {
+		Vec3::from([self[0] / other, self[1] / other, self[2] / other])
+	}
+}
+
+impl Neg for Vec3 {
+	type Output = Vec3;
+
+    
+	fn neg(self) -> Vec3 {
+		Vec3::from([-self[0], -self[1], -self[2]])
+	}
+}
+
 impl Display for Vec3 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
         write!(f, "({}, {}, {})", self[0], self[1], self[2])
     }
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec3};
+
+	#[test]
+	fn cross_follows_right_hand_rule() {
+		let x = Vec3::from([1.0, 0.0, 0.0]);
+		let y = Vec3::from([0.0, 1.0, 0.0]);
+		assert_eq!(x.cross(y), Vec3::from([0.0, 0.0, 1.0]));
+		assert_eq!(y.cross(x), Vec3::from([0.0, 0.0, -1.0]));
+	}
+
+	#[test]
+	fn length_and_normalize() {
+		let vec3 = Vec3::from([3.0, 4.0, 12.0]);
+		assert_eq!(vec3.length_squared(), 169.0);
+		assert_eq!(vec3.length(), 13.0);
+		assert!((vec3.normalize().length() - 1.0).abs() < 0.0001);
+		assert_eq!(Vec3::zero().normalize(), Vec3::zero());
+		assert_eq!(vec3.distance(Vec3::zero()), 13.0);
+	}
+
+	#[test]
+	fn component_helpers() {
+		let a = Vec3::from([1.0, -2.0, 3.0]);
+		let b = Vec3::from([-1.0, 4.0, 5.0]);
+		assert_eq!(a.lerp(b, 0.5), Vec3::from([0.0, 1.0, 4.0]));
+		assert_eq!(a.min(b), Vec3::from([-1.0, -2.0, 3.0]));
+		assert_eq!(a.max(b), Vec3::from([1.0, 4.0, 5.0]));
+		assert_eq!(a.abs(), Vec3::from([1.0, 2.0, 3.0]));
+		assert_eq!(-a, Vec3::from([-1.0, 2.0, -3.0]));
+		assert_eq!(b / 2.0, Vec3::from([-0.5, 2.0, 2.5]));
+	}
+}
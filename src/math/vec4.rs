@@ -1,5 +1,5 @@
 use glium::uniforms::{AsUniformValue, UniformValue};
-use std::ops::{Index, IndexMut, Add, Sub, Mul};
+use std::ops::{Index, IndexMut, Add, Sub, Mul, Div, Neg};
 use std::fmt::{Display, Formatter, Error};
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -42,6 +42,61 @@ impl Vec4 {
 	pub fn get_vals(&self) -> [f32; 4] {
 		self.vals
 	}
+
+    
+	pub fn dot(&self, other: Vec4) -> f32 {
+		let mut sum = 0.0;
+
+		for i in 0..4 {
+			sum += self[i] * other[i];
+		}
+
+		sum
+	}
+
+    
+	pub fn length_squared(&self) -> f32 {
+		self.dot(*self)
+	}
+
+    
+	pub fn length(&self) -> f32 {
+		self.length_squared().sqrt()
+	}
+
+    
+	pub fn normalize(&self) -> Vec4 {
+		let length = self.length();
+		if length == 0.0 {
+			return *self;
+		}
+		*self / length
+	}
+
+    
+	pub fn distance(&self, other: Vec4) -> f32 {
+		(other - *self).length()
+	}
+
+    
+	pub fn lerp(&self, other: Vec4, t: f32) -> Vec4 {
+		*self + (other - *self) * t
+	}
+
+    
+	pub fn min(&self, other: Vec4) -> Vec4 {
+		Vec4::from([self[0].min(other[0]), self[1].min(other[1]), self[2].min(other[2]), self[3].min(other[3])])
+	}
+
+    
+	pub fn max(&self, other: Vec4) -> Vec4 {
+		Vec4::from([self[0].max(other[0]), self[1].max(other[1]), self[2].max(other[2]), self[3].max(other[3])])
+	}
+
+    
+	pub fn abs(&self) -> Vec4 {
+		Vec4::from([self[0].abs(), self[1].abs(), self[2].abs(), self[3].abs()])
+	}
 }
 
 impl From<[f32; 4]> for Vec4 {
@@ -112,8 +167,65 @@ impl Mul<f32> for Vec4 {
 	}
 }
 
+impl Div<Vec4> for Vec4 {
+	type Output = Vec4;
+
+    
+	fn div(self, other: Vec4) -> Vec4 {
+		Vec4::from([self[0] / other[0], self[1] / other[1], self[2] / other[2], self[3] / other[3]])
+	}
+}
+
+impl Div<f32> for Vec4 {
+	type Output = Vec4;
+
+    
+	fn div(self, other: f32) -> Vec4 {
+		Vec4::from([self[0] / other, self[1] / other, self[2] / other, self[3] / other])
+	}
+}
+
+impl Neg for Vec4 {
+	type Output = Vec4;
+
+    
+	fn neg(self) -> Vec4 {
+		Vec4::from([-self[0], -self[1], -self[2], -self[3]])
+	}
+}
+
 impl Display for Vec4 {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error>{
         write!(f, "({}, {}, {}, {})", self[0], self[1], self[2], self[3])
     }
 }
+
+#[cfg(test)]
+mod tests {
+	use math::{Vec4};
+
+	#[test]
+	fn length_and_normalize() {
+		let vec4 = Vec4::from([1.0, 2.0, 2.0, 4.0]);
+		assert_eq!(vec4.length_squared(), 25.0);
+		assert_eq!(vec4.length(), 5.0);
+		assert_eq!(vec4.normalize(), Vec4::from([0.2, 0.4, 0.4, 0.8]));
+		assert_eq!(Vec4::zero().normalize(), Vec4::zero());
+		assert_eq!(vec4.distance(Vec4::zero()), 5.0);
+		assert_eq!(Vec4::x_unit().dot(Vec4::w_unit()), 0.0);
+	}
+
+	#[test]
+	fn component_helpers() {
+		let a = Vec4::from([1.0, -2.0, 3.0, -4.0]);
+		let b = Vec4::from([-1.0, 4.0, 5.0, 2.0]);
+		assert_eq!(a.lerp(b, 0.5), Vec4::from([0.0, 1.0, 4.0, -1.0]));
+		assert_eq!(a.lerp(b, 1.0), b);
+		assert_eq!(a.min(b), Vec4::from([-1.0, -2.0, 3.0, -4.0]));
+		assert_eq!(a.max(b), Vec4::from([1.0, 4.0, 5.0, 2.0]));
+		assert_eq!(a.abs(), Vec4::from([1.0, 2.0, 3.0, 4.0]));
+		assert_eq!(-a, Vec4::from([-1.0, 2.0, -3.0, 4.0]));
+		assert_eq!(b / 2.0, Vec4::from([-0.5, 2.0, 2.5, 1.0]));
+		assert_eq!(b / a, Vec4::from([-1.0, -2.0, 5.0 / 3.0, -0.5]));
+	}
+}